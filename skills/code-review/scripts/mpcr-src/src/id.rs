@@ -3,6 +3,9 @@
 //! Identifiers are intended for:
 //! - `reviewer_id` / `session_id` (8 characters)
 //! - lock owners for `_session.json.lock` (8 characters)
+//!
+//! Prefixed ids (`<prefix>-<hex>`, see [`random_prefixed_id`]) carry a caller-chosen namespace
+//! for log correlation. They are not id8s and are rejected wherever an id8 is required.
 
 use anyhow::Context;
 use rand::RngCore;
@@ -48,6 +51,110 @@ pub fn random_id8() -> anyhow::Result<String> {
     random_hex_id(4)
 }
 
+/// Maximum length of the prefix of a prefixed id.
+pub const MAX_ID_PREFIX_LEN: usize = 16;
+
+fn validate_id_prefix(prefix: &str) -> anyhow::Result<()> {
+    if prefix.is_empty()
+        || prefix.len() > MAX_ID_PREFIX_LEN
+        || !prefix
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        anyhow::bail!(
+            "invalid id prefix {prefix:?} (expected 1-{MAX_ID_PREFIX_LEN} chars of a-z, 0-9, '_')"
+        );
+    }
+    Ok(())
+}
+
+/// Generate a `<prefix>-<hex>` id whose hex part has length `2 * bytes`.
+///
+/// The result is not an id8 (see [`validate_prefixed_id`]).
+///
+/// # Errors
+/// Returns an error if `prefix` is not 1 to [`MAX_ID_PREFIX_LEN`] lowercase ASCII letters,
+/// digits, or `_`, if `bytes` is zero, or if OS randomness cannot be read.
+pub fn random_prefixed_id(prefix: &str, bytes: usize) -> anyhow::Result<String> {
+    validate_id_prefix(prefix)?;
+    if bytes == 0 {
+        anyhow::bail!("prefixed ids need at least 1 random byte");
+    }
+    Ok(format!("{prefix}-{}", random_hex_id(bytes)?))
+}
+
+/// Check that `id` has the shape produced by [`random_prefixed_id`]: a valid prefix, `-`, and
+/// a non-empty, even-length run of lowercase hex digits.
+///
+/// # Errors
+/// Returns an error describing why the id is invalid.
+pub fn validate_prefixed_id(id: &str) -> anyhow::Result<()> {
+    let Some((prefix, hex)) = id.split_once('-') else {
+        anyhow::bail!("invalid prefixed id {id:?} (expected <prefix>-<hex>)");
+    };
+    validate_id_prefix(prefix)?;
+    if hex.is_empty()
+        || hex.len() % 2 != 0
+        || !hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+    {
+        anyhow::bail!("invalid prefixed id {id:?} (expected an even number of lowercase hex digits after '-')");
+    }
+    Ok(())
+}
+
+/// Maximum draws [`random_id8_excluding`] makes before giving up.
+const MAX_ID_ROLLS: usize = 16;
+
+/// Generate an 8-character id for which `taken` is false, re-rolling on a collision.
+///
+/// # Errors
+/// Returns an error if OS randomness cannot be read, or if every one of a bounded number of
+/// draws is taken.
+pub fn random_id8_excluding(taken: impl Fn(&str) -> bool) -> anyhow::Result<String> {
+    first_untaken_id(random_id8, taken)
+}
+
+fn first_untaken_id(
+    mut generate: impl FnMut() -> anyhow::Result<String>,
+    taken: impl Fn(&str) -> bool,
+) -> anyhow::Result<String> {
+    for _ in 0..MAX_ID_ROLLS {
+        let id = generate()?;
+        if !taken(&id) {
+            return Ok(id);
+        }
+    }
+    anyhow::bail!("could not generate an unused id in {MAX_ID_ROLLS} attempts")
+}
+
+/// Generate `count` distinct lowercase hex identifiers of length `2 * bytes`.
+///
+/// Collisions within the batch are retried, so every returned id is unique.
+///
+/// # Errors
+/// Returns an error if OS randomness cannot be read, or if `count` exceeds the number of
+/// distinct ids `bytes` random bytes can represent.
+pub fn random_hex_ids(bytes: usize, count: usize) -> anyhow::Result<Vec<String>> {
+    let space_exceeded = u32::try_from(bytes)
+        .ok()
+        .and_then(|b| b.checked_mul(8))
+        .and_then(|bits| 1_u128.checked_shl(bits))
+        .is_some_and(|space| u128::try_from(count).is_ok_and(|count| count > space));
+    if space_exceeded {
+        anyhow::bail!("cannot generate {count} unique ids from {bytes} random byte(s)");
+    }
+
+    let mut seen = std::collections::HashSet::with_capacity(count);
+    let mut out = Vec::with_capacity(count);
+    while out.len() < count {
+        let id = random_hex_id(bytes)?;
+        if seen.insert(id.clone()) {
+            out.push(id);
+        }
+    }
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +181,47 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn random_hex_ids_are_unique_and_bounded() -> anyhow::Result<()> {
+        let ids = random_hex_ids(1, 256)?;
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        ensure!(unique.len() == 256);
+        ensure!(random_hex_ids(1, 257).is_err());
+        ensure!(random_hex_ids(0, 1)?.len() == 1);
+        Ok(())
+    }
+
+    #[test]
+    fn prefixed_ids_round_trip_through_the_validator() -> anyhow::Result<()> {
+        let id = random_prefixed_id("fleet_a", 3)?;
+        ensure!(id.starts_with("fleet_a-") && id.len() == "fleet_a-".len() + 6);
+        validate_prefixed_id(&id)?;
+
+        ensure!(random_prefixed_id("Fleet", 3).is_err());
+        ensure!(random_prefixed_id("a-b", 3).is_err());
+        ensure!(random_prefixed_id("fleet", 0).is_err());
+        ensure!(validate_prefixed_id("deadbeef").is_err());
+        ensure!(validate_prefixed_id("fleet-abc").is_err());
+        ensure!(validate_prefixed_id("fleet-ABCD").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn first_untaken_id_rerolls_on_collision() -> anyhow::Result<()> {
+        let mut rolls = ["deadbeef", "cafebabe"].into_iter().map(str::to_string);
+        let mut draw = || {
+            rolls
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("scripted ids exhausted"))
+        };
+        let id = first_untaken_id(&mut draw, |id| id == "deadbeef")?;
+        ensure!(id == "cafebabe");
+
+        ensure!(first_untaken_id(|| Ok("deadbeef".to_string()), |_| true).is_err());
+
+        let fresh = random_id8_excluding(|id| id == "deadbeef")?;
+        ensure!(fresh.len() == 8 && fresh != "deadbeef");
+        Ok(())
+    }
 }
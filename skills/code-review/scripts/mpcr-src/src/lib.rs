@@ -5,6 +5,8 @@
 //! - A file-based lock for `_session.json`
 //! - Helpers for computing session paths and writing report files
 //! - Typed read/modify/write operations on `_session.json`
+//! - A structured channel for non-fatal warnings
+//! - Opt-in phase timing for session mutations
 
 /// Random identifier generation (id8 / hex).
 pub mod id;
@@ -14,3 +16,7 @@ pub mod lock;
 pub mod paths;
 /// Session file (`_session.json`) schema and update operations.
 pub mod session;
+/// Opt-in per-phase timing (lock, read, mutate, write) for diagnostics.
+pub mod trace;
+/// Structured non-fatal warnings collected during operations.
+pub mod warnings;
@@ -2,13 +2,21 @@
 //!
 //! The lock is represented by a file named `_session.json.lock` inside the session directory.
 //! Lock acquisition uses `create_new(true)` for exclusivity and retries with exponential backoff.
+//!
+//! With [`LockConfig::fs_lock`], acquisition additionally takes a kernel advisory lock (`flock` on
+//! Unix, `LockFileEx` on Windows) on a `_session.json.flock` sidecar before creating the lock file.
+//! The lock file stays authoritative; the advisory lock is a second line of defense for
+//! filesystems where `create_new` is not atomic across clients (e.g. some NFS setups).
 
+use crate::trace::{self, TracePhase};
 use anyhow::Context;
-use std::fs::{self, OpenOptions};
-use std::io::Write;
+use serde::Serialize;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
+use time::Date;
 
 const DEFAULT_MAX_RETRIES: usize = 8;
 const INITIAL_BACKOFF_MS: u64 = 100;
@@ -19,12 +27,15 @@ const MAX_BACKOFF_MS: u64 = 6_400;
 pub struct LockConfig {
     /// Maximum number of retry attempts when the lock file already exists.
     pub max_retries: usize,
+    /// Also hold an advisory lock on the `_session.json.flock` sidecar (see module docs).
+    pub fs_lock: bool,
 }
 
 impl Default for LockConfig {
     fn default() -> Self {
         Self {
             max_retries: DEFAULT_MAX_RETRIES,
+            fs_lock: false,
         }
     }
 }
@@ -37,6 +48,8 @@ impl Default for LockConfig {
 pub struct LockGuard {
     lock_file: Option<PathBuf>,
     owner: String,
+    // Advisory lock on the sidecar; released when the handle is dropped, after the lock file.
+    fs_lock: Option<File>,
 }
 
 impl LockGuard {
@@ -83,6 +96,15 @@ pub fn lock_file_path(session_dir: &Path) -> PathBuf {
     session_dir.join("_session.json.lock")
 }
 
+/// Compute the path to the advisory lock sidecar (`_session.json.flock`) for `session_dir`.
+///
+/// The sidecar is never removed: deleting a file that others may hold an advisory lock on would
+/// let two holders lock different inodes.
+#[must_use]
+pub fn fs_lock_file_path(session_dir: &Path) -> PathBuf {
+    session_dir.join("_session.json.flock")
+}
+
 /// Release the session lock if `owner` matches the contents of the lock file.
 ///
 /// This is best-effort: if the lock file does not exist, the operation succeeds.
@@ -93,10 +115,48 @@ pub fn release_lock(session_dir: &Path, owner: impl Into<String>) -> anyhow::Res
     let mut guard = LockGuard {
         lock_file: Some(lock_file_path(session_dir)),
         owner: owner.into(),
+        fs_lock: None,
     };
     guard.release_inner()
 }
 
+/// Restart the staleness clock of a lock held by `owner`.
+///
+/// Locks carry no expiry of their own; [`list_locks`] and [`read_lock_state`] age them by the
+/// lock file mtime. Refreshing sets that mtime to now through the handle whose contents were
+/// verified, so a lock that was released and re-acquired by someone else in between is never
+/// touched.
+///
+/// # Errors
+/// Returns an error if there is no lock file, it is held by a different owner, or its mtime
+/// cannot be updated.
+pub fn refresh_lock(session_dir: &Path, owner: &str) -> anyhow::Result<()> {
+    let lock_file = lock_file_path(session_dir);
+    let mut file = match OpenOptions::new().read(true).write(true).open(&lock_file) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(anyhow::anyhow!(
+                "no lock to refresh: {} does not exist",
+                lock_file.display()
+            ));
+        }
+        Err(err) => {
+            return Err(err).with_context(|| format!("open lock file {}", lock_file.display()));
+        }
+    };
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .context("read lock file owner")?;
+    let holder = contents.trim_end();
+    if holder != owner {
+        return Err(anyhow::anyhow!(
+            "lock is held by {holder:?}, not {owner:?}; refusing to refresh"
+        ));
+    }
+    file.set_modified(SystemTime::now())
+        .with_context(|| format!("refresh lock file {}", lock_file.display()))
+}
+
 /// Acquire the session lock and return a guard that releases it on drop.
 ///
 /// If the lock file already exists, this will retry up to `cfg.max_retries` times with exponential
@@ -110,39 +170,290 @@ pub fn acquire_lock(
     cfg: LockConfig,
 ) -> anyhow::Result<LockGuard> {
     let owner = owner.into();
+    trace::timed(TracePhase::LockAcquire, || {
+        acquire_lock_inner(session_dir, &owner, cfg)
+    })
+}
+
+fn acquire_lock_inner(
+    session_dir: &Path,
+    owner: &str,
+    cfg: LockConfig,
+) -> anyhow::Result<LockGuard> {
     let lock_file = lock_file_path(session_dir);
 
     let mut attempt: usize = 0;
     let mut wait_ms: u64 = INITIAL_BACKOFF_MS;
 
     loop {
-        match OpenOptions::new()
-            .write(true)
-            .create_new(true)
-            .open(&lock_file)
-        {
-            Ok(mut f) => {
-                writeln!(f, "{owner}").context("write lock owner")?;
-                f.flush().context("flush lock owner")?;
-                return Ok(LockGuard {
-                    lock_file: Some(lock_file),
-                    owner,
-                });
-            }
-            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
-                if attempt >= cfg.max_retries {
-                    return Err(anyhow::anyhow!("LOCK_TIMEOUT"));
-                }
-                sleep(Duration::from_millis(wait_ms));
-                attempt = attempt.saturating_add(1);
-                wait_ms = (wait_ms.saturating_mul(2)).min(MAX_BACKOFF_MS);
-            }
-            Err(err) => {
-                return Err(err)
-                    .with_context(|| format!("create lock file {}", lock_file.display()))
-            }
+        if let Some(guard) = lock_once(session_dir, &lock_file, owner, cfg)? {
+            return Ok(guard);
+        }
+        if attempt >= cfg.max_retries {
+            return Err(anyhow::anyhow!("LOCK_TIMEOUT"));
+        }
+        sleep(Duration::from_millis(wait_ms));
+        attempt = attempt.saturating_add(1);
+        wait_ms = (wait_ms.saturating_mul(2)).min(MAX_BACKOFF_MS);
+    }
+}
+
+/// Create the lock file once; `Ok(None)` means it already exists.
+fn create_lock_file(lock_file: &Path, owner: &str) -> anyhow::Result<Option<LockGuard>> {
+    match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(lock_file)
+    {
+        Ok(mut f) => {
+            writeln!(f, "{owner}").context("write lock owner")?;
+            f.flush().context("flush lock owner")?;
+            Ok(Some(LockGuard {
+                lock_file: Some(lock_file.to_path_buf()),
+                owner: owner.to_string(),
+                fs_lock: None,
+            }))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("create lock file {}", lock_file.display())),
+    }
+}
+
+/// Take the sidecar advisory lock without blocking; `Ok(None)` means another handle holds it.
+fn try_fs_lock(session_dir: &Path) -> anyhow::Result<Option<File>> {
+    let path = fs_lock_file_path(session_dir);
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&path)
+        .with_context(|| format!("open fs lock file {}", path.display()))?;
+    match file.try_lock() {
+        Ok(()) => Ok(Some(file)),
+        Err(TryLockError::WouldBlock) => Ok(None),
+        Err(TryLockError::Error(err)) => {
+            Err(err).with_context(|| format!("lock fs lock file {}", path.display()))
+        }
+    }
+}
+
+/// One acquisition attempt: the advisory lock first (if enabled), then the lock file.
+fn lock_once(
+    session_dir: &Path,
+    lock_file: &Path,
+    owner: &str,
+    cfg: LockConfig,
+) -> anyhow::Result<Option<LockGuard>> {
+    let fs_lock = if cfg.fs_lock {
+        match try_fs_lock(session_dir)? {
+            Some(file) => Some(file),
+            None => return Ok(None),
+        }
+    } else {
+        None
+    };
+    Ok(create_lock_file(lock_file, owner)?.map(|mut guard| {
+        guard.fs_lock = fs_lock;
+        guard
+    }))
+}
+
+#[derive(Debug)]
+/// Outcome of [`try_acquire_lock`].
+pub enum TryLockOutcome {
+    /// The lock was free and is now held by the caller.
+    Acquired(LockGuard),
+    /// The lock is held by someone else.
+    Held {
+        /// Owner recorded in the lock file (`None` if it vanished or was unreadable mid-probe).
+        owner: Option<String>,
+    },
+}
+
+/// Attempt to acquire the session lock exactly once, without retries or backoff.
+///
+/// When the lock is held, the owner recorded in the lock file is returned instead of an error.
+/// `cfg.max_retries` is ignored; `cfg.fs_lock` is honored.
+///
+/// # Errors
+/// Returns an error if the lock file cannot be created or written for reasons other than
+/// already existing, or if the advisory lock cannot be taken for reasons other than contention.
+pub fn try_acquire_lock(
+    session_dir: &Path,
+    owner: impl Into<String>,
+    cfg: LockConfig,
+) -> anyhow::Result<TryLockOutcome> {
+    let lock_file = lock_file_path(session_dir);
+    if let Some(guard) = lock_once(session_dir, &lock_file, &owner.into(), cfg)? {
+        return Ok(TryLockOutcome::Acquired(guard));
+    }
+    let owner = fs::read_to_string(&lock_file)
+        .ok()
+        .map(|s| s.trim_end().to_string());
+    Ok(TryLockOutcome::Held { owner })
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Current lock state of one session directory (see [`read_lock_state`]).
+pub struct LockState {
+    /// Whether `_session.json.lock` exists.
+    pub locked: bool,
+    /// Owner recorded in the lock file (`None` if unlocked or unreadable).
+    pub owner: Option<String>,
+    /// Seconds since the lock file was last modified (`None` if unlocked or its mtime is
+    /// unavailable).
+    pub age_secs: Option<u64>,
+}
+
+/// Owner and mtime of the lock file at `lock_file`, or `None` if there is no lock file.
+fn lock_info(lock_file: &Path) -> Option<(Option<String>, Option<SystemTime>)> {
+    let meta = fs::metadata(lock_file).ok()?;
+    let owner = fs::read_to_string(lock_file)
+        .ok()
+        .map(|s| s.trim_end().to_string());
+    Some((owner, meta.modified().ok()))
+}
+
+fn age_since(modified: Option<SystemTime>, now: SystemTime) -> Option<Duration> {
+    modified.and_then(|modified| now.duration_since(modified).ok())
+}
+
+/// Remove `lock_file` only if it still records `owner` and `modified`, i.e. it was neither
+/// re-acquired nor refreshed since it was inspected. Returns whether the file was removed.
+fn remove_lock_if_unchanged(
+    lock_file: &Path,
+    owner: Option<&str>,
+    modified: Option<SystemTime>,
+) -> anyhow::Result<bool> {
+    let mut file = match File::open(lock_file) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => {
+            return Err(err).with_context(|| format!("open lock file {}", lock_file.display()));
+        }
+    };
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .with_context(|| format!("read lock file {}", lock_file.display()))?;
+    let current_modified = file
+        .metadata()
+        .with_context(|| format!("stat lock file {}", lock_file.display()))?
+        .modified()
+        .ok();
+    if owner != Some(contents.trim_end()) || modified.is_none() || current_modified != modified {
+        return Ok(false);
+    }
+    match fs::remove_file(lock_file) {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err).with_context(|| format!("remove stale lock {}", lock_file.display())),
+    }
+}
+
+/// Read whether `session_dir` is locked, and by whom, without touching the lock.
+#[must_use]
+pub fn read_lock_state(session_dir: &Path, now: SystemTime) -> LockState {
+    lock_info(&lock_file_path(session_dir)).map_or(
+        LockState {
+            locked: false,
+            owner: None,
+            age_secs: None,
+        },
+        |(owner, modified)| LockState {
+            locked: true,
+            owner,
+            age_secs: age_since(modified, now).map(|age| age.as_secs()),
+        },
+    )
+}
+
+/// Default age after which [`list_locks`] flags a lock as stale.
+pub const DEFAULT_STALE_LOCK_SECS: u64 = 600;
+
+#[derive(Debug, Clone, Copy)]
+/// Options for [`list_locks`].
+pub struct ListLocksOptions {
+    /// Only scan date directories on or after this date (`None` scans all of them).
+    pub since: Option<Date>,
+    /// Locks older than this are flagged `stale`.
+    pub stale_after: Duration,
+    /// Remove stale lock files instead of only reporting them.
+    pub force_release_stale: bool,
+    /// Reference time for lock ages.
+    pub now: SystemTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// A lock file found by [`list_locks`].
+pub struct LockListing {
+    /// Session directory holding the lock.
+    pub session_dir: String,
+    /// Full path to `_session.json.lock`.
+    pub lock_file: String,
+    /// Owner recorded in the lock file (`None` if unreadable).
+    pub owner: Option<String>,
+    /// Seconds since the lock file was last modified (`None` if its mtime is unavailable).
+    pub age_secs: Option<u64>,
+    /// Whether the lock is older than [`ListLocksOptions::stale_after`].
+    pub stale: bool,
+    /// Whether the stale lock file was removed ([`ListLocksOptions::force_release_stale`]).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub released: bool,
+}
+
+/// List held session locks under a reports base directory (`.../code_reviews`).
+///
+/// Only immediate `YYYY-MM-DD` subdirectories are scanned; anything else is ignored. Results are
+/// sorted by session directory. Nothing is modified unless
+/// [`ListLocksOptions::force_release_stale`] is set, in which case stale lock files are removed
+/// regardless of owner, unless the owner or mtime changed since they were listed (re-acquired or
+/// refreshed).
+///
+/// # Errors
+/// Returns an error if `reports_base` cannot be read, or a stale lock cannot be removed.
+pub fn list_locks(reports_base: &Path, opts: ListLocksOptions) -> anyhow::Result<Vec<LockListing>> {
+    let date_fmt =
+        time::format_description::parse("[year]-[month]-[day]").context("parse date format")?;
+    let entries = fs::read_dir(reports_base)
+        .with_context(|| format!("read reports base {}", reports_base.display()))?;
+    let mut session_dirs = Vec::new();
+    for entry in entries {
+        let entry = entry.context("read reports base entry")?;
+        let name = entry.file_name();
+        let Some(date) = name
+            .to_str()
+            .and_then(|name| Date::parse(name, &date_fmt).ok())
+        else {
+            continue;
+        };
+        if opts.since.is_some_and(|since| date < since) || !entry.path().is_dir() {
+            continue;
         }
+        session_dirs.push(entry.path());
     }
+    session_dirs.sort();
+
+    let mut locks = Vec::new();
+    for session_dir in session_dirs {
+        let lock_file = lock_file_path(&session_dir);
+        let Some((owner, modified)) = lock_info(&lock_file) else {
+            continue;
+        };
+        let age = age_since(modified, opts.now);
+        let stale = age.is_some_and(|age| age > opts.stale_after);
+        let released = stale
+            && opts.force_release_stale
+            && remove_lock_if_unchanged(&lock_file, owner.as_deref(), modified)?;
+        locks.push(LockListing {
+            session_dir: session_dir.to_string_lossy().to_string(),
+            lock_file: lock_file.to_string_lossy().to_string(),
+            owner,
+            age_secs: age.map(|age| age.as_secs()),
+            stale,
+            released,
+        });
+    }
+    Ok(locks)
 }
 
 #[cfg(test)]
@@ -170,4 +481,152 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn try_acquire_lock_reports_current_owner() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let session_dir = dir.path();
+
+        let TryLockOutcome::Acquired(guard) =
+            try_acquire_lock(session_dir, "owner-a", LockConfig::default())?
+        else {
+            anyhow::bail!("expected a free lock to be acquired");
+        };
+        let TryLockOutcome::Held { owner } =
+            try_acquire_lock(session_dir, "owner-b", LockConfig::default())?
+        else {
+            anyhow::bail!("expected a held lock to be reported");
+        };
+        ensure!(owner.as_deref() == Some("owner-a"));
+
+        guard.release()?;
+        ensure!(matches!(
+            try_acquire_lock(session_dir, "owner-b", LockConfig::default())?,
+            TryLockOutcome::Acquired(_)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn force_release_removes_stale_locks_and_keeps_fresh_ones() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let stale_dir = dir.path().join("2026-01-10");
+        let fresh_dir = dir.path().join("2026-01-11");
+        fs::create_dir_all(&stale_dir)?;
+        fs::create_dir_all(&fresh_dir)?;
+        fs::write(lock_file_path(&stale_dir), "owner-a\n")?;
+        fs::write(lock_file_path(&fresh_dir), "owner-b\n")?;
+        File::options()
+            .write(true)
+            .open(lock_file_path(&stale_dir))?
+            .set_modified(SystemTime::now() - Duration::from_hours(1))?;
+
+        let locks = list_locks(
+            dir.path(),
+            ListLocksOptions {
+                since: None,
+                stale_after: Duration::from_secs(DEFAULT_STALE_LOCK_SECS),
+                force_release_stale: true,
+                now: SystemTime::now(),
+            },
+        )?;
+        let [stale, fresh] = locks.as_slice() else {
+            anyhow::bail!("expected two locks, got {locks:?}");
+        };
+        ensure!(stale.stale && stale.released);
+        ensure!(!fresh.stale && !fresh.released);
+        ensure!(!lock_file_path(&stale_dir).exists());
+        ensure!(lock_file_path(&fresh_dir).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn remove_lock_if_unchanged_skips_reacquired_or_refreshed_locks() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let lock_file = lock_file_path(dir.path());
+        fs::write(&lock_file, "owner-b\n")?;
+        let modified = fs::metadata(&lock_file)?.modified().ok();
+
+        ensure!(!remove_lock_if_unchanged(
+            &lock_file,
+            Some("owner-a"),
+            modified
+        )?);
+        let listed = modified.map(|at| at - Duration::from_secs(5));
+        ensure!(!remove_lock_if_unchanged(
+            &lock_file,
+            Some("owner-b"),
+            listed
+        )?);
+        ensure!(lock_file.exists());
+
+        ensure!(remove_lock_if_unchanged(
+            &lock_file,
+            Some("owner-b"),
+            modified
+        )?);
+        ensure!(!lock_file.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn refresh_lock_resets_age_for_owner_only() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let session_dir = dir.path();
+        ensure!(refresh_lock(session_dir, "owner-a").is_err());
+
+        let guard = acquire_lock(session_dir, "owner-a", LockConfig::default())?;
+        let hour_ago = SystemTime::now() - Duration::from_hours(1);
+        File::options()
+            .write(true)
+            .open(lock_file_path(session_dir))?
+            .set_modified(hour_ago)?;
+        let aged = read_lock_state(session_dir, SystemTime::now());
+        ensure!(aged.age_secs.is_some_and(|age| age >= 3_599));
+
+        let err = refresh_lock(session_dir, "owner-b")
+            .err()
+            .ok_or_else(|| anyhow::anyhow!("stranger refresh should fail"))?;
+        ensure!(err.to_string().contains("owner-a"));
+        ensure!(read_lock_state(session_dir, SystemTime::now())
+            .age_secs
+            .is_some_and(|age| age >= 3_599));
+
+        refresh_lock(session_dir, "owner-a")?;
+        let refreshed = read_lock_state(session_dir, SystemTime::now());
+        ensure!(refreshed.owner.as_deref() == Some("owner-a"));
+        ensure!(refreshed.age_secs.is_some_and(|age| age < 60));
+
+        guard.release()?;
+        ensure!(refresh_lock(session_dir, "owner-a").is_err());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fs_lock_blocks_second_acquire_even_without_lock_file() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let session_dir = dir.path();
+        let cfg = LockConfig {
+            max_retries: 0,
+            fs_lock: true,
+        };
+
+        let guard = acquire_lock(session_dir, "owner-a", cfg)?;
+        // Simulate a lock file that another client failed to see (non-atomic create_new).
+        fs::remove_file(lock_file_path(session_dir))?;
+
+        ensure!(try_fs_lock(session_dir)?.is_none());
+        ensure!(acquire_lock(session_dir, "owner-b", cfg).is_err());
+        ensure!(matches!(
+            try_acquire_lock(session_dir, "owner-b", cfg)?,
+            TryLockOutcome::Held { .. }
+        ));
+
+        drop(guard);
+        ensure!(fs_lock_file_path(session_dir).exists());
+        let guard = acquire_lock(session_dir, "owner-b", cfg)?;
+        guard.release()?;
+        Ok(())
+    }
 }
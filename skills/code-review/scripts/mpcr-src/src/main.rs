@@ -5,21 +5,31 @@
 //! The actual coordination logic lives in the `mpcr` library crate (`src/session.rs`, `src/lock.rs`, etc).
 
 use anyhow::Context;
-use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 use mpcr::id;
-use mpcr::lock::{self, LockConfig};
+use mpcr::lock::{self, ListLocksOptions, LockConfig, LockListing, TryLockOutcome};
 use mpcr::session::{
-    append_note, collect_reports, finalize_review, load_session, register_reviewer,
-    set_initiator_status, update_review, AppendNoteParams, FinalizeReviewParams, InitiatorStatus,
-    NoteRole, NoteType, RegisterReviewerParams, ReportsFilters, ReportsOptions, ReportsResult,
-    ReportsView, ReviewPhase, ReviewVerdict, ReviewerStatus, SessionLocator,
-    SetInitiatorStatusParams, SeverityCounts, UpdateReviewParams,
+    active_session_id, amend_counts, append_note, apply_batch, close_stale,
+    collect_reports_with_warnings, compact_session, decline_all, filter_review_entries,
+    finalize_review, find_orphan_reports, flatten_report_notes, init_session,
+    list_reviewer_reviews, load_session, load_session_snapshot, note_type_catalog, parse_batch_ops,
+    parse_ts, preview_register_reviewer, register_reviewer, rename_target, report_hint,
+    set_initiator_status, summarize_for_applicator, summarize_reports, touch_review, update_review,
+    updated_at_baseline, validate_id8, wire_name, AmendCountsParams, AppendNoteParams, BatchParams,
+    CloseStaleParams, CompactSessionParams, DeclineAllParams, FinalizeReviewParams,
+    InitSessionParams, InitiatorStatus, NoteRole, NoteType, NoteTypeInfo, RegisterReviewerParams,
+    RenameTargetParams, ReportsFilters, ReportsOptions, ReportsResult, ReportsView, ReviewEntry,
+    ReviewKeyField, ReviewPhase, ReviewVerdict, ReviewerStatus, SessionFile, SessionLocator,
+    SetInitiatorStatusParams, Severity, SeverityCounts, TouchReviewParams, UpdateReviewParams,
+    CURRENT_SCHEMA_VERSION, DEFAULT_REPORT_EXT, REPORT_TABLE_COLUMNS,
 };
-use serde::Serialize;
+use mpcr::trace::{self, TraceEvent};
+use mpcr::warnings::Warnings;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use time::{Date, Month, OffsetDateTime};
+use time::{Date, Month, OffsetDateTime, UtcOffset};
 
 #[derive(Parser)]
 #[command(
@@ -44,11 +54,19 @@ Output path notes:
 Environment variables (optional; only read when `--use-env` is passed):
   MPCR_REPO_ROOT    Repo root used for default session dir (default: auto-detect git root; fallback: cwd)
   MPCR_DATE         Session date (YYYY-MM-DD) used for default session dir (default: today in UTC)
+  MPCR_TZ           UTC offset (e.g. -07:00) used to compute "today" when no date is given (default: UTC)
   MPCR_SESSION_DIR  Explicit session directory containing `_session.json`
   MPCR_REVIEWER_ID  Stable reviewer identity (id8) for this executor
   MPCR_SESSION_ID   Current session id (id8) for reviewer/applicator commands
   MPCR_TARGET_REF   Current target_ref (used by `applicator wait`)
 
+Stdin context (alternative to `--use-env`; only read when `--stdin-json` is passed):
+  A JSON object with any of `session_dir`, `reviewer_id`, `session_id`, `target_ref`, used
+  wherever the matching MPCR_* variable would be. Explicit flags still win. Stdin is consumed,
+  so commands that read stdin (finalize report markdown, `--content-stdin`, `--input-json -`)
+  fail instead; pass those inputs via files.
+  echo '{"session_dir":"<DIR>","reviewer_id":"<ID8>","session_id":"<ID8>"}' | mpcr --stdin-json reviewer update --status IN_PROGRESS
+
 Common flows:
   # Reviewer (explicit flags; recommended for isolated shells)
   mpcr reviewer register --target-ref main --print-env
@@ -62,6 +80,7 @@ Common flows:
 
 "#
 )]
+#[allow(clippy::struct_excessive_bools)]
 struct Cli {
     #[arg(
         long,
@@ -77,6 +96,49 @@ struct Cli {
         help = "Read MPCR_* environment variables for default values (opt-in)."
     )]
     use_env: bool,
+    #[arg(
+        long,
+        global = true,
+        default_value_t = false,
+        conflicts_with = "use_env",
+        help = "Read a JSON context object (session_dir, reviewer_id, session_id, target_ref) from stdin for default values; stdin is then unavailable to the command."
+    )]
+    stdin_json: bool,
+    #[arg(
+        long,
+        global = true,
+        default_value_t = false,
+        help = "On success, print non-fatal warnings as a trailing {\"warnings\": [...]} JSON line on stderr."
+    )]
+    warnings_json: bool,
+    #[arg(
+        long,
+        global = true,
+        default_value_t = false,
+        help = "Print per-phase timings (lock_acquire, read, mutate, write) as a {\"trace\": [...]} JSON line on stderr."
+    )]
+    trace: bool,
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Load default values from a JSON config file (keys: reports_base, tz, poll_initial_secs, poll_max_secs, fs_lock); explicit flags and opted-in MPCR_* env vars win."
+    )]
+    config: Option<PathBuf>,
+    #[arg(
+        long,
+        global = true,
+        value_name = "DIR",
+        help = "Stage the temp file for atomic `_session.json` writes in DIR (e.g. local disk next to a slow mount); used only if DIR is on the same device as the session dir, else falls back to the session dir with a tmp_dir_fallback warning."
+    )]
+    tmp_in: Option<PathBuf>,
+    #[arg(
+        long,
+        global = true,
+        default_value_t = false,
+        help = "Also hold a kernel advisory lock (flock/LockFileEx) on `_session.json.flock` with every session lock, for session dirs on filesystems where exclusive create is unreliable (e.g. NFS). All writers of a session should agree on this."
+    )]
+    fs_lock: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -84,30 +146,95 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Generate IDs (`reviewer_id`, `session_id`, lock owners).
+    #[command(after_long_help = r#"Examples:
+  mpcr id id8
+  # Capture without a trailing newline:
+  REVIEWER_ID="$(mpcr id id8 --no-newline)"
+  mpcr id hex --bytes 16 -n
+  # Several unique ids in one call (newline-separated, or a JSON array with --json):
+  mpcr id batch --count 5
+  mpcr id batch --count 3 --kind hex --bytes 16 --json
+"#)]
     Id {
         #[command(subcommand)]
         command: IdCommands,
+        #[arg(
+            short = 'n',
+            long,
+            global = true,
+            help = "Omit the trailing newline from raw (non-JSON) output, for byte-exact capture."
+        )]
+        no_newline: bool,
     },
     /// Acquire/release the session lock file (`_session.json.lock`).
     Lock {
         #[command(subcommand)]
         command: LockCommands,
     },
-    /// Read session state (`_session.json`) without modifying it.
+    /// Read session state (`_session.json`) and perform light session maintenance.
     Session {
         #[command(subcommand)]
         command: SessionCommands,
     },
-    /// Reviewer operations (register/update/note/finalize).
+    /// Reviewer operations (register/update/note/finalize/list).
     Reviewer {
         #[command(subcommand)]
         command: ReviewerCommands,
     },
-    /// Applicator operations (wait, set `initiator_status`, append notes).
+    /// Applicator operations (wait, summary, set `initiator_status`, append notes).
     Applicator {
         #[command(subcommand)]
         command: ApplicatorCommands,
     },
+    /// Apply several mutations from a JSONL file under one lock, all-or-nothing.
+    #[command(
+        after_long_help = r#"Batch file (one JSON object per line; blank lines are skipped):
+  {"op":"set-status","reviewer_id":"<ID8>","session_id":"<ID8>","initiator_status":"APPLYING"}
+  {"op":"note","reviewer_id":"<ID8>","session_id":"<ID8>","role":"applicator","type":"applied","content":"Fixed in abc123","tags":["perf"]}
+  {"op":"update","reviewer_id":"<ID8>","session_id":"<ID8>","status":"IN_PROGRESS","phase":"SYNTHESIS"}
+
+Notes:
+  - Ops are applied in order to one in-memory copy of `_session.json`, which is written once.
+  - If any op is invalid or targets a missing entry, nothing is written (the error names the op).
+  - `update` also accepts `"clear_phase": true`.
+
+Example:
+  mpcr batch --session-dir <DIR> --file cmds.jsonl
+"#
+    )]
+    Batch {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "JSONL file of operations (set-status, note, update)."
+        )]
+        file: PathBuf,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Lock owner id8 used while updating `_session.json` (default: random)."
+        )]
+        lock_owner: Option<String>,
+    },
+
+    /// Print build metadata: crate version, session schema version, and git commit if known.
+    #[command(after_long_help = r#"Notes:
+  - `git_sha` is taken from MPCR_GIT_SHA at build time and is null when it was not set.
+
+Example:
+  mpcr version --json
+"#)]
+    Version,
+}
+
+#[derive(Debug, Serialize)]
+struct VersionInfo {
+    name: &'static str,
+    version: &'static str,
+    schema_version: &'static str,
+    git_sha: Option<&'static str>,
 }
 
 #[derive(Copy, Clone, Debug, ValueEnum)]
@@ -116,6 +243,20 @@ enum EmitEnvFormat {
     Sh,
 }
 
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum ExportFormat {
+    /// Newline-delimited JSON: one full review entry per line.
+    Ndjson,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum PrintPathMode {
+    /// Print only the absolute report path (no JSON/result output).
+    Only,
+    /// Print the normal result, then the absolute report path on its own final line.
+    Also,
+}
+
 #[derive(Subcommand)]
 enum IdCommands {
     /// Generate an 8-character ASCII id (hex).
@@ -129,6 +270,49 @@ enum IdCommands {
         )]
         bytes: usize,
     },
+    /// Generate a `<prefix>-<hex>` id for namespacing (not an id8; rejected where an id8 is required).
+    Prefixed {
+        #[arg(
+            long,
+            value_name = "PREFIX",
+            help = "Namespace prefix: 1-16 chars of a-z, 0-9, '_'."
+        )]
+        prefix: String,
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 4,
+            help = "Number of random bytes; the part after '-' is 2*N hex characters."
+        )]
+        bytes: usize,
+    },
+    /// Generate several unique ids in one invocation.
+    Batch {
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u64).range(1..),
+            help = "Number of ids to generate; ids are unique within the batch."
+        )]
+        count: u64,
+        #[arg(long, value_enum, default_value_t = IdKind::Id8, help = "Kind of id to generate.")]
+        kind: IdKind,
+        #[arg(
+            long,
+            value_name = "B",
+            required_if_eq("kind", "hex"),
+            help = "With --kind hex, number of random bytes per id (2*B hex characters)."
+        )]
+        bytes: Option<usize>,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum IdKind {
+    /// 8-character hex ids (as from `mpcr id id8`).
+    Id8,
+    /// Hex ids of length 2*B (as from `mpcr id hex --bytes B`).
+    Hex,
 }
 
 #[derive(Subcommand)]
@@ -138,11 +322,28 @@ enum LockCommands {
   # From repo root (or with --repo-root/--date):
   mpcr lock acquire --owner <owner_id8>
 
+  # Let mpcr mint the owner; the output's `owner` is what `lock release` needs:
+  mpcr lock acquire --json | jq -r .owner
+
   # Explicit session directory:
   mpcr lock acquire --session-dir .local/reports/code_reviews/YYYY-MM-DD --owner <owner_id8>
 
+  # Hold the lock only while a command runs (released even if the command fails):
+  mpcr lock acquire --owner <owner_id8> --hold-cmd './scripts/edit-session.sh'
+
+  # Probe without waiting (exit status 3 and the current owner if someone holds it):
+  mpcr lock acquire --owner <owner_id8> --if-free
+
+  # Shared/NFS session dirs: also take an advisory lock while the command runs:
+  mpcr lock acquire --owner <owner_id8> --fs-lock --hold-cmd './scripts/edit-session.sh'
+
 Notes:
   - `lock acquire` leaves the lock held; release it with `lock release` using the same --owner.
+  - Without --owner a random id8 is used; the effective owner is always printed.
+  - With `--hold-cmd`, the lock is released when the command exits and mpcr exits with its status.
+  - With the global `--fs-lock`, a kernel advisory lock on `_session.json.flock` is also taken,
+    as by every session writer run with `--fs-lock`. It is held while `--hold-cmd` runs and
+    otherwise only during acquisition (it is released when mpcr exits).
 "#)]
     Acquire {
         #[command(flatten)]
@@ -150,9 +351,9 @@ Notes:
         #[arg(
             long,
             value_name = "OWNER",
-            help = "Lock owner identifier (recommend: an id8 from `mpcr id id8`)."
+            help = "Lock owner identifier (default: a random id8, printed as `owner`)."
         )]
-        owner: String,
+        owner: Option<String>,
         #[arg(
             long,
             default_value_t = 8,
@@ -160,6 +361,17 @@ Notes:
             help = "Maximum retries with exponential backoff before failing with LOCK_TIMEOUT."
         )]
         max_retries: usize,
+        #[arg(
+            long,
+            value_name = "CMD",
+            help = "Run CMD via the platform shell while holding the lock, then release it."
+        )]
+        hold_cmd: Option<String>,
+        #[arg(
+            long,
+            help = "Try exactly once; if the lock is held, print {\"acquired\": false, \"owner\": ...} and exit with status 3."
+        )]
+        if_free: bool,
     },
     /// Release the session lock file if you are the current owner.
     #[command(after_long_help = r#"Examples:
@@ -176,21 +388,87 @@ Notes:
         )]
         owner: String,
     },
+    /// Restart the staleness clock of a lock you hold, without releasing it.
+    #[command(after_long_help = r#"Examples:
+  mpcr lock refresh --owner <owner_id8>
+  mpcr lock refresh --session-dir .local/reports/code_reviews/YYYY-MM-DD --owner <owner_id8>
+
+Notes:
+  - Lock age (as shown by `lock list` and flagged against --stale-after-secs) is measured from the
+    lock file mtime; refresh resets it to now. Long-running holders should refresh periodically.
+  - Fails if the lock is gone or held by a different owner; the lock file is left untouched.
+"#)]
+    Refresh {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "OWNER",
+            help = "Lock owner identifier (must match the contents of `_session.json.lock`)."
+        )]
+        owner: String,
+    },
+    /// List held session locks across every date directory under a reports base.
+    #[command(after_long_help = r#"Examples:
+  mpcr lock list --reports-base .local/reports/code_reviews
+  mpcr lock list --reports-base .local/reports/code_reviews --scan-days 7 --json
+
+  # Remove locks left behind by crashed agents:
+  mpcr lock list --reports-base .local/reports/code_reviews --force-release-stale
+
+Notes:
+  - Each lock reports its owner (the lock file contents) and age (from the lock file mtime).
+  - Locks older than --stale-after-secs are flagged `stale`; nothing is removed unless
+    --force-release-stale is passed, which removes stale locks regardless of owner (a lock
+    re-acquired or refreshed after it was listed is kept).
+"#)]
+    List {
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Reports base directory containing YYYY-MM-DD session directories."
+        )]
+        reports_base: PathBuf,
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u64).range(1..),
+            help = "Only scan the last N days of date directories (today counts as day 1)."
+        )]
+        scan_days: Option<u64>,
+        #[arg(
+            long,
+            value_name = "SECS",
+            default_value_t = lock::DEFAULT_STALE_LOCK_SECS,
+            help = "Flag locks older than SECS as stale."
+        )]
+        stale_after_secs: u64,
+        #[arg(long, help = "Remove stale lock files (regardless of owner).")]
+        force_release_stale: bool,
+    },
 }
 
 #[derive(Subcommand)]
 enum SessionCommands {
-    /// Print the parsed `_session.json`.
+    /// Print the parsed `_session.json` plus `lock_file`/`locked` lock state.
     #[command(after_long_help = r#"Examples:
   # From repo root (or with --repo-root/--date):
   mpcr session show
 
   # Explicit session directory:
   mpcr session show --session-dir .local/reports/code_reviews/YYYY-MM-DD
+
+  # Debug a session file that fails strict parsing (unknown fields, wrong types):
+  mpcr session show --raw
 "#)]
     Show {
         #[command(flatten)]
         session: SessionDirArgs,
+        #[arg(
+            long,
+            help = "Pretty-print `_session.json` as generic JSON without schema validation or lock state (prints the text verbatim and fails if it is not JSON)."
+        )]
+        raw: bool,
     },
     /// Report-oriented session views (open/closed/in-progress).
     #[command(after_long_help = r#"Examples:
@@ -200,16 +478,195 @@ enum SessionCommands {
 
   # Filter examples:
   mpcr session reports open --include-notes --only-with-notes
+  mpcr session reports open --include-notes --dedupe-notes
+  mpcr session reports open --flatten-notes --json
+  mpcr session reports open --pretty-notes
+  for ref in $(mpcr session reports open --target-refs-only); do ...; done
+  mpcr session reports open --distinct-reviewers --json
+  mpcr session reports open --include-lock-state --json
+  mpcr session reports closed --tsv | cut -f1,5
   mpcr session reports closed --only-with-report --include-report-contents
+  mpcr session reports closed --only-actionable
+  mpcr session reports closed --report-grep 'parse_config' --ignore-case
   mpcr session reports open --reviewer-status IN_PROGRESS,BLOCKED
+  mpcr session reports open --target-ref-glob 'feat/*'
+  mpcr session reports open --exclude-reviewer-id <id8>
   mpcr session reports closed --initiator-status RECEIVED --verdict APPROVE
+  mpcr session reports closed --min-severity major
+  mpcr session reports open --note-min-severity major
+  mpcr session reports open --phase-at-least ADVERSARIAL_PROOFS
+  mpcr session reports closed --include-report-size
+  mpcr session reports open --open-older-than 1h
+  mpcr session reports open --include-age --json
+  mpcr session reports closed --include-history --json
+  mpcr session reports closed --finished-after 2026-01-01T00:00:00Z --finished-before 2026-02-01T00:00:00Z
+  mpcr session reports open --count-by-phase --json
+  mpcr session reports closed --verdict-summary --json
+  mpcr session reports closed --json-lines --json-lines-header
+
+  # Replay against a saved copy of _session.json:
+  mpcr session reports open --as-of /tmp/_session.snapshot.json
 
   # Explicit session directory:
   mpcr session reports closed --session-dir .local/reports/code_reviews/YYYY-MM-DD --include-report-contents --json
 "#)]
     Reports {
         #[command(subcommand)]
-        command: ReportsCommands,
+        command: Box<ReportsCommands>,
+    },
+    /// Export full review entries (not summaries) matching the report filters.
+    #[command(after_long_help = r#"Notes:
+  - Each line is one complete `reviews[]` entry from `_session.json`, including its `notes`.
+  - All `session reports` filters apply; there is no open/closed view (combine
+    --reviewer-status to narrow by status).
+
+Examples:
+  mpcr session export --format ndjson > reviews.ndjson
+  mpcr session export --format ndjson --reviewer-status FINISHED --verdict BLOCK
+"#)]
+    Export {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_enum,
+            value_name = "FORMAT",
+            help = "Output format (`ndjson`: one JSON entry per line)."
+        )]
+        format: ExportFormat,
+        #[command(flatten)]
+        filters: Box<ReportsFilterArgs>,
+    },
+    /// Refresh a review entry's `updated_at` without changing anything else.
+    #[command(after_long_help = r#"Notes:
+  - Only `updated_at` is changed; status, phase, and notes are left untouched.
+  - Terminal entries (FINISHED, CANCELLED, ERROR) cannot be touched.
+
+Example:
+  mpcr session touch --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8>
+"#)]
+    Touch {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Reviewer id for the entry you are touching (id8)."
+        )]
+        reviewer_id: Option<String>,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Session id for the entry you are touching (id8)."
+        )]
+        session_id: Option<String>,
+    },
+    /// Rewrite `target_ref` on every review entry that matches `--from`.
+    #[command(after_long_help = r#"Notes:
+  - Matching is exact; all matching entries are rewritten in one atomic write.
+  - Entries are never merged; session ids, statuses, and report file names are unchanged.
+
+Example:
+  mpcr session rename-target --from main --to release/2.0
+"#)]
+    RenameTarget {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "REF",
+            help = "Target ref to replace (exact match)."
+        )]
+        from: String,
+        #[arg(long, value_name = "REF", help = "Replacement target ref.")]
+        to: String,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Lock owner id8 used while updating `_session.json` (default: random)."
+        )]
+        lock_owner: Option<String>,
+    },
+    /// Create the session dir and an empty `_session.json` before any reviewer registers.
+    #[command(after_long_help = r#"Notes:
+  - Writes `repo_root` (canonicalized) and `session_date` with no reviewers, under the lock.
+  - If `_session.json` already exists it is left untouched (`created: false`).
+
+Example:
+  mpcr session init --repo-root . --date 2026-01-11 --json
+"#)]
+    Init {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Lock owner id8 used while creating `_session.json` (default: random)."
+        )]
+        lock_owner: Option<String>,
+    },
+    /// Rewrite `_session.json` in canonical form (deterministic key order and whitespace).
+    #[command(after_long_help = r#"Notes:
+  - The file is parsed strictly and reserialized exactly as mpcr writes it; content is unchanged.
+  - Already-canonical files are not rewritten (`changed: false`).
+
+Example:
+  mpcr session compact --session-dir .local/reports/code_reviews/YYYY-MM-DD
+"#)]
+    Compact {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Lock owner id8 used while updating `_session.json` (default: random)."
+        )]
+        lock_owner: Option<String>,
+    },
+    /// List files in the session dir not referenced by any review's `report_file` (read-only).
+    #[command(after_long_help = r#"Notes:
+  - Nothing is locked or deleted; use the list to audit before removing files by hand.
+  - `_session.json` (and its lock/temp files) and `notes_archive.jsonl` are never listed.
+
+Example:
+  mpcr session orphan-reports --session-dir .local/reports/code_reviews/YYYY-MM-DD --json
+"#)]
+    OrphanReports {
+        #[command(flatten)]
+        session: SessionDirArgs,
+    },
+    /// Mark abandoned (idle, non-terminal) reviews as ERROR so the session can be finalized.
+    #[command(after_long_help = r#"Notes:
+  - A review is stale when it is not FINISHED/CANCELLED/ERROR and its `updated_at` is older
+    than --older-than.
+  - Each stale entry becomes ERROR with `finished_at` set and an `error_detail` note recording
+    its previous status and last update; all entries are closed in one atomic write.
+  - --dry-run lists the entries that would be closed without locking or writing.
+
+Examples:
+  mpcr session close-stale --older-than 2h --dry-run
+  mpcr session close-stale --older-than 30m --json
+"#)]
+    CloseStale {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "DURATION",
+            help = "Close reviews whose `updated_at` is older than this (e.g. 30m, 2h, 1d)."
+        )]
+        older_than: String,
+        #[arg(
+            long,
+            help = "List the reviews that would be closed without changing anything."
+        )]
+        dry_run: bool,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Lock owner id8 used while updating `_session.json` (default: random)."
+        )]
+        lock_owner: Option<String>,
     },
 }
 
@@ -230,28 +687,207 @@ struct SessionDirArgs {
     #[arg(
         long,
         value_name = "YYYY-MM-DD",
-        help = "Session date used to compute the default session dir (default: today in UTC; set for determinism)."
+        help = "Session date used to compute the default session dir (default: today in UTC or --tz; set for determinism)."
     )]
     date: Option<String>,
+    #[arg(
+        long,
+        value_name = "OFFSET",
+        allow_hyphen_values = true,
+        help = "UTC offset (e.g. -07:00, +05:30, UTC) used to compute \"today\" when --date is not set (default: UTC)."
+    )]
+    tz: Option<String>,
 }
 
 struct ResolvedSessionInput {
     session_dir: PathBuf,
     repo_root: PathBuf,
     session_date: Date,
+    /// Offset from `--tz` / `MPCR_TZ` / config (UTC when unset).
+    tz: UtcOffset,
 }
 
+/// Output modes (`tsv`, `json_lines`, `single`, ...) join the `output_mode` group, so at most one
+/// of them can be selected.
 #[derive(Args)]
+#[command(group(ArgGroup::new("output_mode").multiple(false)))]
 #[allow(clippy::struct_excessive_bools)]
 struct ReportsArgs {
     #[command(flatten)]
     session: SessionDirArgs,
+    #[arg(
+        long,
+        value_name = "SNAPSHOT",
+        help = "Read reviews from this saved copy of `_session.json` instead of the live session file (report paths still resolve against the session dir)."
+    )]
+    as_of: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Resolve repo-relative report files (report_path, contents, size, grep) against DIR instead of the stored repo_root, e.g. for a session synced from another machine."
+    )]
+    report_path_base: Option<PathBuf>,
+    #[command(flatten)]
+    filters: ReportsFilterArgs,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Emit a flat list with one record per note (tagged with reviewer_id/session_id/target_ref) instead of grouped reviews (implies --include-notes)."
+    )]
+    flatten_notes: bool,
+    #[arg(long, help = "Include full notes for each review entry.")]
+    include_notes: bool,
+    #[arg(
+        long,
+        help = "Collapse notes identical in role, type, content, and timestamp (output only; notes_count reflects the collapsed count)."
+    )]
+    dedupe_notes: bool,
+    #[arg(
+        long,
+        help = "Replace included note contents with \"[redacted]\" (role, type, timestamp, tags, and notes_count are kept)."
+    )]
+    redact: bool,
+    #[arg(
+        long,
+        help = "Add reviewer_notes_count and applicator_notes_count (notes per author role) to each review entry."
+    )]
+    note_author_count: bool,
+    #[arg(
+        long,
+        visible_alias = "include-report",
+        help = "Include report markdown contents for each review entry (if available)."
+    )]
+    include_report_contents: bool,
+    #[arg(
+        long,
+        help = "Include report_bytes (report file size, via metadata only) for each review entry."
+    )]
+    include_report_size: bool,
+    #[arg(
+        long,
+        help = "Include updated_age_secs/started_age_secs (seconds since updated_at/started_at; null if unparseable) for each review entry."
+    )]
+    include_age: bool,
+    #[arg(
+        long,
+        help = "Include status_history (recorded reviewer-status transitions, oldest first; [] when none) for each review entry."
+    )]
+    include_history: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Emit one compact JSON review summary per line (NDJSON) instead of a single object."
+    )]
+    json_lines: bool,
+    #[arg(
+        long,
+        requires = "json_lines",
+        help = "With --json-lines, emit a leading header line with totals/filters."
+    )]
+    json_lines_header: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Emit tab-separated rows with a header (reviewer_id, session_id, target_ref, status, verdict, blocker, major, minor, nit)."
+    )]
+    tsv: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        conflicts_with_all = [
+            "include_notes",
+            "include_report_contents",
+            "include_report_size",
+            "include_age",
+            "include_history",
+        ],
+        help = "Emit only phase_counts (matching entries per phase, plus a `null` bucket) instead of review summaries."
+    )]
+    count_by_phase: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        conflicts_with_all = [
+            "include_notes",
+            "include_report_contents",
+            "include_report_size",
+            "include_age",
+            "include_history",
+        ],
+        help = "Closed view only: emit verdict_summary (matching entries per verdict, plus a `none` bucket, and a total) instead of review summaries."
+    )]
+    verdict_summary: bool,
+    #[arg(
+        long,
+        help = "Exit nonzero when no reviews match (the normal output is still written first)."
+    )]
+    fail_if_empty: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["json_lines", "tsv", "flatten_notes"],
+        help = "Human output only: show review/note timestamps at the --tz offset (default UTC) instead of UTC; stored and --json output stay UTC."
+    )]
+    local_time: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Human output only: print each review with its notes as plain text (role, type, timestamp, then the content; string content unquoted, objects pretty-printed). Implies --include-notes."
+    )]
+    pretty_notes: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Print only the distinct target refs of matching reviews, sorted (one per line; `{\"target_refs\": [...]}` with --json)."
+    )]
+    target_refs_only: bool,
+    #[arg(
+        long,
+        help = "Attach lock_state (locked, owner, age_secs of `_session.json.lock`) to the result."
+    )]
+    include_lock_state: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Print only `{\"count\": N, \"reviewer_ids\": [...]}`: the distinct reviewer ids of matching reviews, sorted."
+    )]
+    distinct_reviewers: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Print only aggregates over matching reviews (status/verdict/phase counts and severity_totals), without the reviews array."
+    )]
+    summary_only: bool,
+    #[arg(
+        long,
+        group = "output_mode",
+        help = "Print only the matching review summary object; errors unless exactly one review matches."
+    )]
+    single: bool,
+    #[arg(
+        long,
+        value_name = "PTR",
+        group = "output_mode",
+        help = "Print only the value at this RFC 6901 JSON pointer into the result (e.g. /matching_reviews); errors if it does not resolve."
+    )]
+    json_pointer: Option<String>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+struct ReportsFilterArgs {
     #[arg(
         long,
         value_name = "REF",
         help = "If set, only include reviews matching this target_ref."
     )]
     target_ref: Option<String>,
+    #[arg(
+        long,
+        value_name = "GLOB",
+        conflicts_with = "target_ref",
+        help = "Only include reviews whose target_ref matches GLOB (`*` any run, `?` one char; `/` is not special)."
+    )]
+    target_ref_glob: Option<String>,
     #[arg(
         long,
         value_name = "ID8",
@@ -264,9 +900,23 @@ struct ReportsArgs {
         help = "If set, only include reviews matching this reviewer_id."
     )]
     reviewer_id: Option<String>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Only include reviews whose reviewer_id is listed in PATH (one id8 per line; blank lines skipped); unions with --reviewer-id."
+    )]
+    reviewer_id_file: Option<PathBuf>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "ID8",
+        help = "Exclude reviews from this reviewer_id (comma-separated or repeatable)."
+    )]
+    exclude_reviewer_id: Vec<String>,
     #[arg(
         long,
         value_enum,
+        ignore_case = true,
         value_delimiter = ',',
         num_args = 1..,
         value_name = "STATUS",
@@ -276,50 +926,147 @@ struct ReportsArgs {
     #[arg(
         long,
         value_enum,
+        ignore_case = true,
         value_delimiter = ',',
         num_args = 1..,
         value_name = "STATUS",
         help = "Filter by initiator status (comma-separated or repeatable)."
     )]
-    initiator_status: Vec<InitiatorStatus>,
+    initiator_status: Vec<InitiatorStatus>,
+    #[arg(
+        long,
+        value_enum,
+        ignore_case = true,
+        value_delimiter = ',',
+        num_args = 1..,
+        value_name = "VERDICT",
+        help = "Filter by verdict (comma-separated or repeatable)."
+    )]
+    verdict: Vec<ReviewVerdict>,
+    #[arg(
+        long,
+        value_enum,
+        ignore_case = true,
+        value_delimiter = ',',
+        num_args = 1..,
+        value_name = "PHASE",
+        help = "Filter by review phase (comma-separated or repeatable)."
+    )]
+    phase: Vec<ReviewPhase>,
+    #[arg(
+        long,
+        value_enum,
+        ignore_case = true,
+        value_name = "PHASE",
+        help = "Only include reviews whose current_phase is at or beyond PHASE (null phases are excluded)."
+    )]
+    phase_at_least: Option<ReviewPhase>,
+    #[arg(long, help = "Only include reviews that already have a report file.")]
+    only_with_report: bool,
+    #[arg(
+        long,
+        value_name = "TEXT",
+        help = "Only include reviews whose report file contains TEXT (reads each report; unreadable reports are skipped with a warning)."
+    )]
+    report_grep: Option<String>,
+    #[arg(
+        long,
+        requires = "report_grep",
+        help = "Match --report-grep case-insensitively."
+    )]
+    ignore_case: bool,
+    #[arg(
+        long,
+        help = "Only include reviews that contain at least one note (implies --include-notes)."
+    )]
+    only_with_notes: bool,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "TAG",
+        help = "Only include reviews with a note tagged TAG (comma-separated or repeatable; any tag matches)."
+    )]
+    note_tag: Vec<String>,
+    #[arg(
+        long,
+        help = "Closed view only: keep reviews the applicator still has to act on (initiator_status not APPLIED/CANCELLED)."
+    )]
+    only_actionable: bool,
+    #[arg(
+        long,
+        value_enum,
+        ignore_case = true,
+        value_name = "SEVERITY",
+        help = "Only include reviews with at least one finding at or above this severity."
+    )]
+    min_severity: Option<Severity>,
+    #[arg(
+        long,
+        value_enum,
+        ignore_case = true,
+        value_name = "SEVERITY",
+        help = "Only include reviews with at least one note (see `note --severity`) at or above this severity."
+    )]
+    note_min_severity: Option<Severity>,
+    #[arg(
+        long,
+        value_name = "DURATION",
+        help = "Only include reviews whose newest note is within DURATION (e.g. 30m, 1h, 2d); reviews without notes are excluded."
+    )]
+    note_since: Option<String>,
+    #[arg(
+        long,
+        value_name = "SNAPSHOT",
+        help = "Only include reviews that are new or whose updated_at advanced since this saved copy of `_session.json` (matched by reviewer_id + session_id)."
+    )]
+    changed_since: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "DURATION",
+        help = "Only include open reviews not updated within DURATION (e.g. 30m, 1h, 2d; `open` view only)."
+    )]
+    open_older_than: Option<String>,
+    #[arg(
+        long,
+        value_name = "DURATION",
+        help = "Only include open reviews started more than DURATION ago (e.g. 4h, 2d), each annotated with breach_secs past the threshold (`open` view only)."
+    )]
+    sla_breach: Option<String>,
+    #[arg(
+        long,
+        value_name = "RFC3339",
+        value_parser = parse_ts,
+        help = "Only include reviews finished at or after this time (unfinished reviews are excluded)."
+    )]
+    finished_after: Option<OffsetDateTime>,
     #[arg(
         long,
-        value_enum,
-        value_delimiter = ',',
-        num_args = 1..,
-        value_name = "VERDICT",
-        help = "Filter by verdict (comma-separated or repeatable)."
+        value_name = "RFC3339",
+        value_parser = parse_ts,
+        help = "Only include reviews finished before this time (unfinished reviews are excluded)."
     )]
-    verdict: Vec<ReviewVerdict>,
+    finished_before: Option<OffsetDateTime>,
     #[arg(
         long,
         value_enum,
+        ignore_case = true,
         value_delimiter = ',',
         num_args = 1..,
-        value_name = "PHASE",
-        help = "Filter by review phase (comma-separated or repeatable)."
-    )]
-    phase: Vec<ReviewPhase>,
-    #[arg(long, help = "Only include reviews that already have a report file.")]
-    only_with_report: bool,
-    #[arg(
-        long,
-        help = "Only include reviews that contain at least one note (implies --include-notes)."
+        value_name = "FIELD",
+        help = "Sort by this composite key, components in priority order (comma-separated or repeatable)."
     )]
-    only_with_notes: bool,
-    #[arg(long, help = "Include full notes for each review entry.")]
-    include_notes: bool,
+    review_key: Vec<ReviewKeyField>,
     #[arg(
         long,
-        visible_alias = "include-report",
-        help = "Include report markdown contents for each review entry (if available)."
+        requires = "review_key",
+        help = "Keep only the most recently updated review for each --review-key value."
     )]
-    include_report_contents: bool,
+    unique: bool,
 }
 
 #[derive(Subcommand)]
 enum ReportsCommands {
-    /// Reviews not in a terminal status (`INITIALIZING`, `IN_PROGRESS`, `BLOCKED`).
+    /// Reviews not in a terminal status (`INITIALIZING`, `IN_PROGRESS`, `BLOCKED`, `PAUSED`).
     Open(ReportsArgs),
     /// Reviews in a terminal status (`FINISHED`, `CANCELLED`, `ERROR`).
     Closed(ReportsArgs),
@@ -343,22 +1090,61 @@ enum ReviewerCommands {
   # Worktree / uncommitted review (no commit yet):
   mpcr reviewer register --target-ref 'worktree:feature/foo (uncommitted)' --print-env
 
+  # Ref computed into a file by CI (no shell quoting involved):
+  mpcr reviewer register --target-ref-file target-ref.txt --print-env
+
   # Explicit date and repo root:
   mpcr reviewer register --target-ref pr/123 --repo-root /path/to/repo --date 2026-01-11
 
   # Override the session directory location:
   mpcr reviewer register --target-ref main --session-dir .local/reports/code_reviews/YYYY-MM-DD
+
+  # Review spanning midnight: keep using yesterday's session while it is still active:
+  mpcr reviewer register --target-ref main --join-session-dir .local/reports/code_reviews/<YESTERDAY> --print-env
+
+  # Preview ids / session join without writing anything:
+  mpcr reviewer register --target-ref main --reviewer-id <id8> --dry-run
+
+  # Handoff: chain from the previous agent's MPCR_REVIEWER_ID:
+  mpcr --use-env reviewer register --target-ref main --parent-from-env --print-env
+
+  # Cap how many reviewers may join the session:
+  mpcr reviewer register --target-ref main --max-reviewers 3 --print-env
+
+  # Watch without holding up `applicator wait`:
+  mpcr reviewer register --target-ref main --observe-only --print-env
+
+  # Show where the eventual report will be written:
+  mpcr reviewer register --target-ref main --print-report-hint --json
+
+  # Join the orchestrator's MPCR_SESSION_ID if set, else join/start a session as usual:
+  mpcr --use-env reviewer register --target-ref main --session-id-from-env --print-env
 "#)]
     Register {
         #[arg(
             long,
             value_name = "REF",
+            required_unless_present = "target_ref_file",
+            conflicts_with = "target_ref_file",
             help = "Target reference being reviewed (branch name, PR ref, commit, etc)."
         )]
-        target_ref: String,
+        target_ref: Option<String>,
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Read the target reference from this file (surrounding whitespace trimmed) instead of --target-ref; avoids shell quoting."
+        )]
+        target_ref_file: Option<PathBuf>,
 
         #[command(flatten)]
         session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "DIR",
+            conflicts_with = "session_dir",
+            help = "Register into this existing (e.g. prior-date) session dir if its _session.json holds an active session for the target ref; otherwise (no session file or no active session) register into the usual dir. Unlike --session-dir, never creates a session there: fails if that session closes before registration takes the lock."
+        )]
+        join_session_dir: Option<PathBuf>,
 
         #[arg(
             long,
@@ -378,6 +1164,34 @@ enum ReviewerCommands {
             help = "Optional parent reviewer id for handoff/chaining (8-character ASCII alphanumeric)."
         )]
         parent_id: Option<String>,
+        #[arg(
+            long,
+            help = "With --use-env, default --parent-id to MPCR_REVIEWER_ID (the previous agent) instead of reusing it as this reviewer's id."
+        )]
+        parent_from_env: bool,
+        #[arg(
+            long,
+            help = "With --use-env, default --session-id to MPCR_SESSION_ID when set (else join active session for target_ref, else random)."
+        )]
+        session_id_from_env: bool,
+        #[arg(
+            long,
+            help = "Reject target refs containing control characters or newlines (off by default for exotic refs)."
+        )]
+        strict_ref: bool,
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u64).range(1..),
+            help = "Fail if registering would give the session more than N distinct reviewers (re-registering is always allowed)."
+        )]
+        max_reviewers: Option<u64>,
+
+        #[arg(
+            long,
+            help = "Register as a non-blocking observer (`blocking: false`): `applicator wait` does not wait on this entry."
+        )]
+        observe_only: bool,
 
         #[arg(
             long,
@@ -393,9 +1207,22 @@ enum ReviewerCommands {
             help = "Print MPCR_* key/value lines for manual reuse (does not emit `export`)."
         )]
         print_env: bool,
+
+        #[arg(
+            long,
+            help = "Preview the ids/session dir registration would use without writing anything."
+        )]
+        dry_run: bool,
+
+        #[arg(
+            long,
+            conflicts_with_all = ["emit_env", "print_env"],
+            help = "Also return report_hint: the session dir and the expected report filename pattern (time prefix as {HH-MM-SS-mmm})."
+        )]
+        print_report_hint: bool,
     },
 
-    /// Update your reviewer-owned status and/or current phase.
+    /// Update your reviewer-owned status, current phase, and/or ETA.
     #[command(after_long_help = r#"Reviewer statuses:
   INITIALIZING  Registered; review not yet started
   IN_PROGRESS   Actively reviewing
@@ -403,6 +1230,7 @@ enum ReviewerCommands {
   CANCELLED     Stopped early
   ERROR         Fatal error; see notes for details
   BLOCKED       Waiting on an external dependency or intervention
+  PAUSED        Set aside by the reviewer (context switch); will resume
 
 Review phases:
   INGESTION, DOMAIN_COVERAGE, THEOREM_GENERATION, ADVERSARIAL_PROOFS, SYNTHESIS, REPORT_WRITING
@@ -411,6 +1239,10 @@ Examples:
   # Recommended (explicit flags):
   mpcr reviewer update --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --status IN_PROGRESS --phase INGESTION
   mpcr reviewer update --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --clear-phase
+  mpcr reviewer update --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --eta 2026-01-11T15:30:00Z
+  mpcr reviewer update --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --bump-only
+
+An update that sets nothing errors ("nothing to update") unless --bump-only is given.
 "#)]
     Update {
         #[command(flatten)]
@@ -448,6 +1280,25 @@ Examples:
             help = "Clear current review phase (sets `current_phase` to null)."
         )]
         clear_phase: bool,
+        #[arg(
+            long,
+            value_name = "RFC3339",
+            value_parser = parse_ts,
+            help = "Set your estimated completion time so the applicator can decide whether to wait."
+        )]
+        eta: Option<OffsetDateTime>,
+        #[arg(
+            long,
+            conflicts_with = "eta",
+            help = "Clear the estimated completion time (removes `eta`)."
+        )]
+        clear_eta: bool,
+        #[arg(
+            long,
+            conflicts_with_all = ["status", "phase", "clear_phase", "eta", "clear_eta"],
+            help = "Only refresh `updated_at` (an update with no fields otherwise errors)."
+        )]
+        bump_only: bool,
     },
 
     /// Finalize a review: write the report markdown and mark the review entry FINISHED.
@@ -457,6 +1308,17 @@ Examples:
 Report input:
   - Use `--report-file <path>` to read markdown from a file
   - Or omit it and pipe markdown via stdin
+  - Or pass `--no-report` for a verdict-only closure (no file; report_file stays null)
+  - Empty/whitespace-only markdown is rejected unless `--allow-empty-report` is set
+  - `--template <path>` wraps the markdown: `{{body}}` is replaced by it, and `{{reviewer_id}}`,
+    `{{session_id}}`, `{{target_ref}}`, `{{verdict}}`, `{{date}}`, `{{finished_at}}`, and
+    `{{counts.blocker|major|minor|nit}}` by entry values; unknown placeholders are an error
+
+JSON input:
+  - `--input-json <path|->` reads `{"verdict": ..., "counts": {...}, "report_markdown": ...}`
+    (every field optional; missing counts default to 0)
+  - Explicit flags (--verdict, --blocker/--major/--minor/--nit, --report-file) override the
+    object; malformed or unknown fields fail before anything is written
 
 Examples:
   mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --verdict APPROVE --blocker 0 --major 0 --minor 0 --nit 0 <<'EOF'
@@ -465,7 +1327,17 @@ Examples:
   EOF
 
   mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --verdict APPROVE --report-file review.md
+  # Safe retry after a crash (identical report is a no-op):
+  mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --verdict APPROVE --report-file review.md --update-if-finished
   cat review.md | mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --verdict REQUEST_CHANGES --major 2
+  # Open the written report directly:
+  $EDITOR "$(mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --verdict APPROVE --report-file review.md --print-path)"
+  # Single agent reviewing and applying: mark the review consumed in the same write:
+  mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --verdict APPROVE --report-file review.md --initiator-status REVIEWED
+  # Standard front matter from a shared template:
+  mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --verdict APPROVE --report-file review.md --template report-template.md
+  # Everything from one JSON object:
+  jq -n '{verdict: "APPROVE", counts: {minor: 1}, report_markdown: "..."}' | mpcr reviewer finalize --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --input-json -
 "#)]
     Finalize {
         #[command(flatten)]
@@ -487,49 +1359,142 @@ Examples:
             value_enum,
             ignore_case = true,
             value_name = "VERDICT",
+            required_unless_present = "input_json",
             help = "Final verdict to record in the session entry."
         )]
-        verdict: ReviewVerdict,
+        verdict: Option<ReviewVerdict>,
+        #[arg(long, help = "Number of BLOCKER findings in the report (default: 0).")]
+        blocker: Option<u64>,
+        #[arg(long, help = "Number of MAJOR findings in the report (default: 0).")]
+        major: Option<u64>,
+        #[arg(long, help = "Number of MINOR findings in the report (default: 0).")]
+        minor: Option<u64>,
+        #[arg(long, help = "Number of NIT findings in the report (default: 0).")]
+        nit: Option<u64>,
         #[arg(
             long,
-            default_value_t = 0,
-            help = "Number of BLOCKER findings in the report."
+            value_name = "PATH",
+            conflicts_with_all = ["blocker", "major", "minor", "nit"],
+            help = "Read counts from a JSON object {\"blocker\", \"major\", \"minor\", \"nit\"} (missing keys are 0; unknown keys error)."
         )]
-        blocker: u64,
+        counts_file: Option<PathBuf>,
         #[arg(
             long,
-            default_value_t = 0,
-            help = "Number of MAJOR findings in the report."
+            value_name = "PATH",
+            help = "Read report markdown from this file (if omitted, reads from stdin)."
         )]
-        major: u64,
+        report_file: Option<PathBuf>,
         #[arg(
             long,
-            default_value_t = 0,
-            help = "Number of MINOR findings in the report."
+            value_name = "PATH",
+            help = "Read verdict, counts, and report markdown from a JSON object (`-` for stdin); explicit flags override its fields."
         )]
-        minor: u64,
+        input_json: Option<PathBuf>,
         #[arg(
             long,
-            default_value_t = 0,
-            help = "Number of NIT findings in the report."
+            conflicts_with = "no_report",
+            help = "If updating `_session.json` fails after the report was written, delete the report so a retry starts clean."
         )]
-        nit: u64,
+        cleanup_on_failure: bool,
         #[arg(
             long,
             value_name = "PATH",
-            help = "Read report markdown from this file (if omitted, reads from stdin)."
+            conflicts_with = "no_report",
+            help = "Wrap the report in this template (`{{body}}` and entry placeholders; see `--help`)."
         )]
-        report_file: Option<PathBuf>,
+        template: Option<PathBuf>,
+        #[arg(
+            long,
+            help = "If already finalized, succeed when the existing report is identical (updates verdict/counts only); with --no-report, replace verdict/counts on an already-finished entry."
+        )]
+        update_if_finished: bool,
+        #[arg(
+            long,
+            value_name = "EXT",
+            default_value = DEFAULT_REPORT_EXT,
+            help = "Report file extension without the dot (ASCII alphanumeric, e.g. md, txt, html)."
+        )]
+        report_ext: String,
+        #[arg(
+            long,
+            value_enum,
+            value_name = "MODE",
+            num_args = 0..=1,
+            default_missing_value = "only",
+            help = "Print the absolute report path as a bare line (`only`, the default, replaces the result; `also` appends it)."
+        )]
+        print_path: Option<PrintPathMode>,
+        #[arg(
+            long,
+            conflicts_with_all = ["report_file", "print_path"],
+            help = "Finalize with verdict/counts only: write no report file and leave report_file null."
+        )]
+        no_report: bool,
+        #[arg(
+            long,
+            conflicts_with = "no_report",
+            help = "Write the report even if the markdown is empty or whitespace-only (rejected by default)."
+        )]
+        allow_empty_report: bool,
+        #[arg(
+            long,
+            value_enum,
+            ignore_case = true,
+            value_name = "INITIATOR_STATUS",
+            help = "Also set initiator_status in the same write that marks the review FINISHED (default: unchanged)."
+        )]
+        initiator_status: Option<InitiatorStatus>,
+    },
+
+    /// Correct the severity counts on a FINISHED review entry.
+    #[command(after_long_help = r#"Notes:
+  - All four counts are required and replace the recorded tallies.
+  - Verdict, report file, and finished_at are unchanged; an `acknowledged` note records the
+    previous and corrected counts.
+  - Fails unless the entry's status is FINISHED.
+
+Example:
+  mpcr reviewer amend-counts --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --blocker 0 --major 1 --minor 2 --nit 0
+"#)]
+    AmendCounts {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Your reviewer_id (id8). Capture from `mpcr reviewer register --print-env`."
+        )]
+        reviewer_id: Option<String>,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Session id (id8). Capture from `mpcr reviewer register --print-env`."
+        )]
+        session_id: Option<String>,
+        #[arg(long, help = "Corrected number of BLOCKER findings.")]
+        blocker: u64,
+        #[arg(long, help = "Corrected number of MAJOR findings.")]
+        major: u64,
+        #[arg(long, help = "Corrected number of MINOR findings.")]
+        minor: u64,
+        #[arg(long, help = "Corrected number of NIT findings.")]
+        nit: u64,
     },
 
     /// Append a reviewer note to the session entry.
     #[command(after_long_help = r#"Note content:
   - By default, `--content` is stored as a JSON string.
   - With `--content-json`, `--content` must be valid JSON (object/array/string/number/etc).
+  - With `--content-stdin`, the entire stdin is used instead of `--content`.
+  - With `--max-notes N`, the oldest notes are pruned so at most N remain; add
+    `--archive-pruned-notes` to append them to `notes_archive.jsonl` in the session dir.
 
 Examples:
   mpcr reviewer note --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --note-type question --content "Can you clarify X?"
   mpcr reviewer note --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --note-type domain_observation --content-json --content '{"domain":"security","note":"..."}'
+  mpcr reviewer note --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --note-type domain_observation --tag perf --content "Hot loop allocates per item"
+  mpcr reviewer note --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --note-type domain_observation --severity major --content "Unchecked index on user input"
+  jq -n '{domain:"security"}' | mpcr reviewer note --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --note-type domain_observation --content-json --content-stdin
 "#)]
     Note {
         #[command(flatten)]
@@ -558,12 +1523,80 @@ Examples:
         #[arg(
             long,
             value_name = "TEXT",
+            required_unless_present = "content_stdin",
             help = "Note content (string by default, or JSON when --content-json is set)."
         )]
-        content: String,
-        #[arg(long, help = "Interpret --content as JSON instead of a plain string.")]
+        content: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "content",
+            help = "Read the note content from stdin instead of --content (avoids argv length limits)."
+        )]
+        content_stdin: bool,
+        #[arg(
+            long,
+            help = "Interpret the content (--content or --content-stdin) as JSON instead of a plain string."
+        )]
         content_json: bool,
+        #[arg(
+            long,
+            help = "Skip the append if the most recent note has the same role, type, and content."
+        )]
+        dedupe: bool,
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u64).range(1..),
+            help = "After appending, keep only the newest N notes on the entry (drops the oldest)."
+        )]
+        max_notes: Option<u64>,
+        #[arg(
+            long,
+            requires = "max_notes",
+            help = "Append notes dropped by --max-notes to `notes_archive.jsonl` in the session dir."
+        )]
+        archive_pruned_notes: bool,
+        #[arg(
+            long = "tag",
+            value_name = "TAG",
+            help = "Label the note with a short slug (a-z, 0-9, '-', '_'; max 32 chars). Repeatable."
+        )]
+        tag: Vec<String>,
+        #[arg(
+            long,
+            value_enum,
+            ignore_case = true,
+            value_name = "SEVERITY",
+            help = "Severity of this note's observation (independent of the final report counts)."
+        )]
+        severity: Option<Severity>,
+    },
+
+    /// List your own reviews (open and closed) in the session, oldest `updated_at` first.
+    #[command(after_long_help = r#"Notes:
+  - Equivalent to the union of `session reports open` and `session reports closed` filtered by
+    --reviewer-id; a missing `_session.json` yields an empty list.
+
+Examples:
+  mpcr reviewer list --session-dir <DIR> --reviewer-id <ID8>
+  mpcr --use-env reviewer list
+"#)]
+    List {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Your reviewer_id (id8). Capture from `mpcr reviewer register --print-env`."
+        )]
+        reviewer_id: Option<String>,
     },
+
+    /// List note types (`--note-type` values) with when to use each.
+    #[command(after_long_help = r#"Example:
+  mpcr reviewer note-types --json
+"#)]
+    NoteTypes,
 }
 
 #[derive(Subcommand)]
@@ -607,14 +1640,58 @@ Example:
         lock_owner: Option<String>,
     },
 
+    /// Decline all findings of a terminal review: set APPLIED and record one `declined` note.
+    #[command(after_long_help = r#"Notes:
+  - The review must be terminal (FINISHED, CANCELLED, ERROR).
+  - initiator_status becomes APPLIED and the reason is stored as a single applicator
+    `declined` note, in one atomic write.
+
+Example:
+  mpcr applicator decline-all --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --reason "Out of scope for this release"
+"#)]
+    DeclineAll {
+        #[command(flatten)]
+        session: SessionDirArgs,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Reviewer id for the entry you are updating (id8)."
+        )]
+        reviewer_id: Option<String>,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Session id for the entry you are updating (id8)."
+        )]
+        session_id: Option<String>,
+        #[arg(
+            long,
+            value_name = "TEXT",
+            help = "Why the findings are declined (stored as the note content)."
+        )]
+        reason: String,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "Lock owner id8 used while updating `_session.json` (default: random)."
+        )]
+        lock_owner: Option<String>,
+    },
+
     /// Append an applicator note to a review entry.
     #[command(after_long_help = r#"Note content:
   - By default, `--content` is stored as a JSON string.
   - With `--content-json`, `--content` must be valid JSON.
+  - With `--content-stdin`, the entire stdin is used instead of `--content`.
+  - With `--max-notes N`, the oldest notes are pruned so at most N remain.
+  - With `--set-initiator-status`, initiator_status is updated in the same locked write.
 
 Example:
   # Recommended (explicit flags):
   mpcr applicator note --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --note-type applied --content "Fixed in commit abc123"
+
+  # Record the note and mark the review applied in one step:
+  mpcr applicator note --session-dir <DIR> --reviewer-id <ID8> --session-id <ID8> --note-type applied --content "Fixed in commit abc123" --set-initiator-status APPLIED
 "#)]
     Note {
         #[command(flatten)]
@@ -643,11 +1720,61 @@ Example:
         #[arg(
             long,
             value_name = "TEXT",
+            required_unless_present = "content_stdin",
             help = "Note content (string by default, or JSON when --content-json is set)."
         )]
-        content: String,
-        #[arg(long, help = "Interpret --content as JSON instead of a plain string.")]
+        content: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "content",
+            help = "Read the note content from stdin instead of --content (avoids argv length limits)."
+        )]
+        content_stdin: bool,
+        #[arg(
+            long,
+            help = "Interpret the content (--content or --content-stdin) as JSON instead of a plain string."
+        )]
         content_json: bool,
+        #[arg(
+            long,
+            help = "Skip the append if the most recent note has the same role, type, and content."
+        )]
+        dedupe: bool,
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u64).range(1..),
+            help = "After appending, keep only the newest N notes on the entry (drops the oldest)."
+        )]
+        max_notes: Option<u64>,
+        #[arg(
+            long,
+            requires = "max_notes",
+            help = "Append notes dropped by --max-notes to `notes_archive.jsonl` in the session dir."
+        )]
+        archive_pruned_notes: bool,
+        #[arg(
+            long = "tag",
+            value_name = "TAG",
+            help = "Label the note with a short slug (a-z, 0-9, '-', '_'; max 32 chars). Repeatable."
+        )]
+        tag: Vec<String>,
+        #[arg(
+            long,
+            value_enum,
+            ignore_case = true,
+            value_name = "SEVERITY",
+            help = "Severity of this note's observation (independent of the final report counts)."
+        )]
+        severity: Option<Severity>,
+        #[arg(
+            long,
+            value_enum,
+            ignore_case = true,
+            value_name = "INITIATOR_STATUS",
+            help = "Also set initiator_status in the same write as the note (e.g. APPLIED)."
+        )]
+        set_initiator_status: Option<InitiatorStatus>,
         #[arg(
             long,
             value_name = "ID8",
@@ -656,32 +1783,83 @@ Example:
         lock_owner: Option<String>,
     },
 
+    /// Summarize the session for the applicator (read-only).
+    #[command(after_long_help = r#"Output:
+  total_reviews, verdict breakdown, total blockers/majors, and `actionable`:
+  reviews in a terminal reviewer status whose initiator_status is not APPLIED/CANCELLED.
+
+Example:
+  mpcr applicator summary --session-dir <DIR> --json
+"#)]
+    Summary {
+        #[command(flatten)]
+        session: SessionDirArgs,
+    },
+
+    /// List note types (`--note-type` values) with when to use each.
+    #[command(after_long_help = r#"Example:
+  mpcr applicator note-types --json
+"#)]
+    NoteTypes,
+
     /// Block until matching reviews reach a terminal status.
     #[command(after_long_help = r#"Terminal reviewer statuses:
   FINISHED, CANCELLED, ERROR
 
+Open entries registered with `reviewer register --observe-only` (`blocking: false`) are not
+waited on.
+
 Examples:
   # From repo root (or with --repo-root/--date), wait for *all* reviews:
   mpcr applicator wait
 
   # Explicit flags (recommended):
   mpcr applicator wait --session-dir <DIR> --target-ref main --session-id <ID8>
+
+  # Start on the first review that finishes (prints its reviewer_id/session_id):
+  mpcr applicator wait --session-dir <DIR> --target-ref main --any
+
+  # Tune polling (backoff doubles from the initial delay up to the cap):
+  mpcr applicator wait --session-dir <DIR> --poll-initial-secs 0.25 --poll-max-secs 5
 "#)]
     Wait {
         #[command(flatten)]
         session: SessionDirArgs,
         #[arg(
             long,
-            value_name = "REF",
-            help = "If set, only wait for reviews matching this target_ref."
+            value_name = "REF",
+            help = "If set, only wait for reviews matching this target_ref."
+        )]
+        target_ref: Option<String>,
+        #[arg(
+            long,
+            value_name = "ID8",
+            help = "If set, only wait for reviews matching this session_id."
+        )]
+        session_id: Option<String>,
+        #[arg(
+            long,
+            help = "Return as soon as any matching review is terminal and report which one (default: wait for all)."
+        )]
+        any: bool,
+        #[arg(
+            long,
+            value_name = "SECS",
+            help = format!(
+                "Initial delay between polls in seconds (fractions allowed; doubles after each poll) [default: {}].",
+                PollBackoff::DEFAULT_INITIAL_SECS
+            )
         )]
-        target_ref: Option<String>,
+        poll_initial_secs: Option<f64>,
         #[arg(
             long,
-            value_name = "ID8",
-            help = "If set, only wait for reviews matching this session_id."
+            value_name = "SECS",
+            help = format!(
+                "Maximum delay between polls in seconds (must be >= --poll-initial-secs) [default: {}].",
+                PollBackoff::DEFAULT_MAX_SECS
+            )
         )]
-        session_id: Option<String>,
+        poll_max_secs: Option<f64>,
     },
 }
 
@@ -690,6 +1868,48 @@ struct OkResult {
     ok: bool,
 }
 
+/// `--trace` output line.
+#[derive(Debug, Serialize)]
+struct TraceLine {
+    trace: Vec<TraceEvent>,
+}
+
+/// `reviewer note-types` / `applicator note-types` output.
+#[derive(Debug, Serialize)]
+struct NoteTypesResult {
+    note_types: Vec<NoteTypeInfo>,
+}
+
+/// `session show` output: the session file plus current lock state.
+#[derive(Debug, Serialize)]
+struct SessionShowResult {
+    #[serde(flatten)]
+    session: SessionFile,
+    lock_file: String,
+    locked: bool,
+}
+
+/// Exit status for `lock acquire --if-free` when the lock is already held.
+const LOCK_HELD_EXIT_CODE: i32 = 3;
+
+#[derive(Debug, Serialize)]
+struct LockAcquireResult {
+    ok: bool,
+    owner: String,
+}
+
+#[derive(Debug, Serialize)]
+struct LockProbeResult {
+    acquired: bool,
+    owner: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct LockListResult {
+    reports_base: String,
+    locks: Vec<LockListing>,
+}
+
 fn main() {
     if let Err(err) = run() {
         eprintln!("{err:?}");
@@ -697,91 +1917,451 @@ fn main() {
     }
 }
 
-#[allow(clippy::too_many_lines)]
 fn run() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    let warnings_json = cli.warnings_json;
+    let trace_enabled = cli.trace;
+    let config = match cli.config.as_deref() {
+        Some(path) => ConfigDefaults::load(path)?,
+        None => ConfigDefaults::default(),
+    };
+    let stdin_context = if cli.stdin_json {
+        Some(StdinContext::load()?)
+    } else {
+        None
+    };
+    let mut warnings = Warnings::new();
+    let command = || run_command(cli, &config, stdin_context.as_ref(), &mut warnings);
+    let (result, trace) = if trace_enabled {
+        let (result, events) = trace::record(command);
+        (result, Some(events))
+    } else {
+        (command(), None)
+    };
+    if let Some(trace) = trace {
+        // Emitted even when the command fails: slow failures are worth diagnosing too.
+        let mut stderr = std::io::stderr().lock();
+        write_json_line(&mut stderr, &TraceLine { trace })?;
+        stderr.flush().context("flush stderr")?;
+    }
+    result?;
+    if warnings_json {
+        let mut stderr = std::io::stderr().lock();
+        write_json_line(&mut stderr, &warnings)?;
+        stderr.flush().context("flush stderr")?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+fn run_command(
+    cli: Cli,
+    config: &ConfigDefaults,
+    stdin: Option<&StdinContext>,
+    warnings: &mut Warnings,
+) -> anyhow::Result<()> {
     let json = cli.json;
-    let use_env = cli.use_env;
+    let env = EnvSource {
+        use_env: cli.use_env,
+        stdin,
+    };
+    let tmp_in = cli.tmp_in;
+    let fs_lock = cli.fs_lock || config.fs_lock == Some(true);
     let now = OffsetDateTime::now_utc();
 
     match cli.command {
-        Commands::Id { command } => match command {
-            IdCommands::Id8 => {
-                let out = id::random_id8()?;
-                if json {
-                    write_json(&out)?;
-                } else {
-                    println!("{out}");
-                }
-            }
-            IdCommands::Hex { bytes } => {
-                let out = id::random_hex_id(bytes)?;
-                if json {
-                    write_json(&out)?;
-                } else {
-                    println!("{out}");
+        Commands::Id {
+            command,
+            no_newline,
+        } => {
+            let out = match command {
+                IdCommands::Id8 => id::random_id8()?,
+                IdCommands::Hex { bytes } => id::random_hex_id(bytes)?,
+                IdCommands::Prefixed { prefix, bytes } => id::random_prefixed_id(&prefix, bytes)?,
+                IdCommands::Batch { count, kind, bytes } => {
+                    let bytes = match (kind, bytes) {
+                        (IdKind::Id8, None) => 4,
+                        (IdKind::Id8, Some(_)) => {
+                            return Err(anyhow::anyhow!("--bytes requires --kind hex"));
+                        }
+                        (IdKind::Hex, Some(bytes)) => bytes,
+                        (IdKind::Hex, None) => {
+                            return Err(anyhow::anyhow!("--kind hex requires --bytes"));
+                        }
+                    };
+                    let ids = id::random_hex_ids(bytes, usize::try_from(count)?)?;
+                    if json {
+                        write_json(&ids)?;
+                        return Ok(());
+                    }
+                    ids.join("\n")
                 }
+            };
+            if json {
+                write_json(&out)?;
+            } else if no_newline {
+                let mut stdout = std::io::stdout().lock();
+                stdout.write_all(out.as_bytes()).context("write stdout")?;
+                stdout.flush().context("flush stdout")?;
+            } else {
+                println!("{out}");
             }
-        },
+        }
 
         Commands::Lock { command } => match command {
             LockCommands::Acquire {
                 session,
                 owner,
                 max_retries,
+                hold_cmd,
+                if_free,
             } => {
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
-                let cfg = LockConfig { max_retries };
-                let guard = lock::acquire_lock(&resolved.session_dir, owner, cfg)?;
-                std::mem::forget(guard);
-                write_ok(json)?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let owner = match owner {
+                    Some(owner) => owner,
+                    None => id::random_id8()?,
+                };
+                let cfg = LockConfig {
+                    max_retries,
+                    fs_lock,
+                };
+                let acquired = LockAcquireResult {
+                    ok: true,
+                    owner: owner.clone(),
+                };
+                let guard = if if_free {
+                    match lock::try_acquire_lock(&resolved.session_dir, owner, cfg)? {
+                        TryLockOutcome::Acquired(guard) => guard,
+                        TryLockOutcome::Held { owner } => {
+                            write_result(
+                                json,
+                                &LockProbeResult {
+                                    acquired: false,
+                                    owner,
+                                },
+                            )?;
+                            std::io::stdout().flush().context("flush stdout")?;
+                            std::process::exit(LOCK_HELD_EXIT_CODE);
+                        }
+                    }
+                } else {
+                    lock::acquire_lock(&resolved.session_dir, owner, cfg)?
+                };
+                let Some(hold_cmd) = hold_cmd else {
+                    std::mem::forget(guard);
+                    return write_result(json, &acquired);
+                };
+                let status = run_shell_command(&hold_cmd);
+                guard.release()?;
+                let status = status?;
+                if !status.success() {
+                    std::process::exit(status.code().map_or(1, std::convert::identity));
+                }
+                write_result(json, &acquired)?;
             }
             LockCommands::Release { session, owner } => {
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
                 lock::release_lock(&resolved.session_dir, owner)?;
                 write_ok(json)?;
             }
+            LockCommands::Refresh { session, owner } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                lock::refresh_lock(&resolved.session_dir, &owner)?;
+                write_ok(json)?;
+            }
+            LockCommands::List {
+                reports_base,
+                scan_days,
+                stale_after_secs,
+                force_release_stale,
+            } => {
+                let since = scan_days
+                    .map(|days| {
+                        i64::try_from(days.saturating_sub(1))
+                            .ok()
+                            .and_then(|back| now.date().checked_sub(time::Duration::days(back)))
+                            .ok_or_else(|| anyhow::anyhow!("--scan-days is out of range"))
+                    })
+                    .transpose()?;
+                let locks = lock::list_locks(
+                    &reports_base,
+                    ListLocksOptions {
+                        since,
+                        stale_after: std::time::Duration::from_secs(stale_after_secs),
+                        force_release_stale,
+                        now: now.into(),
+                    },
+                )?;
+                write_result(
+                    json,
+                    &LockListResult {
+                        reports_base: reports_base.to_string_lossy().to_string(),
+                        locks,
+                    },
+                )?;
+            }
         },
 
         Commands::Session { command } => match command {
-            SessionCommands::Show { session } => {
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
-                let session = load_session(&SessionLocator::new(resolved.session_dir))?;
-                write_result(json, &session)?;
+            SessionCommands::Show { session, raw } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let locator = SessionLocator::new(resolved.session_dir);
+                if raw {
+                    return show_raw_session(&locator);
+                }
+                let session = load_session(&locator)?;
+                let lock_file = locator.lock_file();
+                write_result(
+                    json,
+                    &SessionShowResult {
+                        session,
+                        locked: lock_file.exists(),
+                        lock_file: lock_file.to_string_lossy().to_string(),
+                    },
+                )?;
             }
-            SessionCommands::Reports { command } => match command {
+            SessionCommands::Reports { command } => match *command {
                 ReportsCommands::Open(args) => {
-                    handle_reports(use_env, json, now.date(), ReportsView::Open, args)?;
+                    handle_reports(env, config, json, now, ReportsView::Open, args, warnings)?;
                 }
                 ReportsCommands::Closed(args) => {
-                    handle_reports(use_env, json, now.date(), ReportsView::Closed, args)?;
+                    handle_reports(env, config, json, now, ReportsView::Closed, args, warnings)?;
                 }
                 ReportsCommands::InProgress(args) => {
-                    handle_reports(use_env, json, now.date(), ReportsView::InProgress, args)?;
+                    handle_reports(
+                        env,
+                        config,
+                        json,
+                        now,
+                        ReportsView::InProgress,
+                        args,
+                        warnings,
+                    )?;
                 }
             },
+            SessionCommands::Export {
+                session,
+                format: ExportFormat::Ndjson,
+                filters,
+            } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let locator = SessionLocator::new(resolved.session_dir);
+                let filters = filters.into_filters(None)?;
+                if !locator.session_file().exists() {
+                    return Ok(());
+                }
+                let session_data = load_session(&locator)?;
+                let entries =
+                    filter_review_entries(&session_data, &locator, &filters, now, warnings)?;
+                let mut stdout = std::io::stdout().lock();
+                for entry in entries {
+                    write_json_line(&mut stdout, entry)?;
+                }
+                stdout.flush().context("flush stdout")?;
+            }
+            SessionCommands::Touch {
+                session,
+                reviewer_id,
+                session_id,
+            } => {
+                let reviewer_id =
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                let session_id =
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                touch_review(&TouchReviewParams {
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
+                    reviewer_id,
+                    session_id,
+                    now,
+                })?;
+                write_ok(json)?;
+            }
+            SessionCommands::RenameTarget {
+                session,
+                from,
+                to,
+                lock_owner,
+            } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let lock_owner = match lock_owner {
+                    Some(lock_owner) => lock_owner,
+                    None => id::random_id8()?,
+                };
+                let res = rename_target(&RenameTargetParams {
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
+                    from,
+                    to,
+                    now,
+                    lock_owner,
+                })?;
+                write_result(json, &res)?;
+            }
+            SessionCommands::Init {
+                session,
+                lock_owner,
+            } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let lock_owner = match lock_owner {
+                    Some(lock_owner) => lock_owner,
+                    None => id::random_id8()?,
+                };
+                let res = init_session(&InitSessionParams {
+                    repo_root: resolved.repo_root,
+                    session_date: resolved.session_date,
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
+                    lock_owner,
+                })?;
+                write_result(json, &res)?;
+            }
+            SessionCommands::Compact {
+                session,
+                lock_owner,
+            } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let lock_owner = match lock_owner {
+                    Some(lock_owner) => lock_owner,
+                    None => id::random_id8()?,
+                };
+                let res = compact_session(&CompactSessionParams {
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
+                    lock_owner,
+                })?;
+                write_result(json, &res)?;
+            }
+            SessionCommands::OrphanReports { session } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let res = find_orphan_reports(&SessionLocator::new(resolved.session_dir))?;
+                write_result(json, &res)?;
+            }
+            SessionCommands::CloseStale {
+                session,
+                older_than,
+                dry_run,
+                lock_owner,
+            } => {
+                let older_than_secs = parse_duration_secs(&older_than)?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let lock_owner = match lock_owner {
+                    Some(lock_owner) => lock_owner,
+                    None => id::random_id8()?,
+                };
+                let res = close_stale(&CloseStaleParams {
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
+                    older_than_secs,
+                    dry_run,
+                    now,
+                    lock_owner,
+                })?;
+                write_result(json, &res)?;
+            }
         },
 
         Commands::Reviewer { command } => match command {
             ReviewerCommands::Register {
                 target_ref,
+                target_ref_file,
                 session,
+                join_session_dir,
                 reviewer_id,
                 session_id,
                 parent_id,
+                parent_from_env,
+                session_id_from_env,
+                strict_ref,
+                max_reviewers,
+                observe_only,
                 emit_env,
                 print_env,
+                dry_run,
+                print_report_hint,
             } => {
+                if parent_from_env && !env.use_env {
+                    return Err(anyhow::anyhow!("--parent-from-env requires --use-env"));
+                }
+                if session_id_from_env && !env.use_env {
+                    return Err(anyhow::anyhow!("--session-id-from-env requires --use-env"));
+                }
+                let target_ref = match (target_ref, target_ref_file) {
+                    (Some(target_ref), _) => target_ref,
+                    (None, Some(path)) => read_target_ref_file(&path)?,
+                    (None, None) => {
+                        return Err(anyhow::anyhow!(
+                            "missing --target-ref; pass --target-ref or --target-ref-file"
+                        ));
+                    }
+                };
                 let target_ref_for_env = target_ref.clone();
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
+                let mut resolved = resolve_session_input(env, config, &session, now)?;
+                // Registration re-checks the joined session under the lock, so one that closes in
+                // between is refused rather than restarted in the joined dir.
+                let mut joined = false;
+                if let Some(dir) = join_session_dir {
+                    let candidate = SessionLocator::new(dir.clone());
+                    if candidate.session_file().is_file() {
+                        let existing = load_session(&candidate).with_context(|| {
+                            format!("read --join-session-dir {}", dir.display())
+                        })?;
+                        if active_session_id(&existing, &target_ref).is_some() {
+                            resolved.session_date = parse_date_ymd(&existing.session_date)?;
+                            resolved.session_dir = dir;
+                            joined = true;
+                        }
+                    }
+                }
                 let repo_root_for_env = resolved.repo_root.to_string_lossy().to_string();
                 let date_for_env = resolved.session_date.to_string();
-                let session = SessionLocator::new(resolved.session_dir);
+                let session =
+                    write_locator(resolved.session_dir, tmp_in.as_deref(), fs_lock, warnings);
 
-                let reviewer_id =
-                    reviewer_id.or_else(|| opt_env_string(use_env, "MPCR_REVIEWER_ID"));
+                // With --parent-from-env, MPCR_REVIEWER_ID names the previous agent in the chain,
+                // so it becomes the parent rather than this reviewer's own id.
+                let (reviewer_id, parent_id) = if parent_from_env {
+                    (
+                        reviewer_id,
+                        parent_id.or_else(|| opt_env_string(env, "MPCR_REVIEWER_ID")),
+                    )
+                } else {
+                    (
+                        reviewer_id.or_else(|| opt_env_string(env, "MPCR_REVIEWER_ID")),
+                        parent_id,
+                    )
+                };
+
+                // Precedence: --session-id, then MPCR_SESSION_ID (opt-in), then the active
+                // session for target_ref, then a random id.
+                let session_id = if session_id_from_env {
+                    session_id.or_else(|| opt_env_string(env, "MPCR_SESSION_ID"))
+                } else {
+                    session_id
+                };
 
-                let res = register_reviewer(RegisterReviewerParams {
+                let params = RegisterReviewerParams {
                     repo_root: resolved.repo_root,
                     session_date: resolved.session_date,
                     session,
@@ -789,8 +2369,24 @@ fn run() -> anyhow::Result<()> {
                     reviewer_id,
                     session_id,
                     parent_id,
+                    strict_ref,
+                    max_reviewers: max_reviewers.map(usize::try_from).transpose()?,
+                    observe_only,
+                    require_active_session: joined,
                     now,
-                })?;
+                };
+                let mut res = if dry_run {
+                    preview_register_reviewer(params)?
+                } else {
+                    register_reviewer(params)?
+                };
+                if print_report_hint {
+                    res.report_hint = Some(report_hint(
+                        &res.session_dir,
+                        &target_ref_for_env,
+                        &res.reviewer_id,
+                    ));
+                }
                 match emit_env {
                     Some(EmitEnvFormat::Sh) => write_env_sh(&[
                         ("MPCR_REPO_ROOT", repo_root_for_env.as_str()),
@@ -829,23 +2425,34 @@ fn run() -> anyhow::Result<()> {
                 status,
                 phase,
                 clear_phase,
+                eta,
+                clear_eta,
+                bump_only,
             } => {
                 let reviewer_id =
-                    require_arg_or_env(reviewer_id, use_env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
                 let session_id =
-                    require_arg_or_env(session_id, use_env, "MPCR_SESSION_ID", "--session-id")?;
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
                 let phase = if clear_phase {
                     Some(None)
                 } else {
                     phase.map(Some)
                 };
+                let eta = if clear_eta { Some(None) } else { eta.map(Some) };
                 let params = UpdateReviewParams {
-                    session: SessionLocator::new(resolved.session_dir),
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
                     reviewer_id,
                     session_id,
                     status,
                     phase,
+                    eta,
+                    bump_only,
                     now,
                 };
                 update_review(&params)?;
@@ -862,33 +2469,93 @@ fn run() -> anyhow::Result<()> {
                 minor,
                 nit,
                 report_file,
+                template,
+                update_if_finished,
+                report_ext,
+                print_path,
+                no_report,
+                allow_empty_report,
+                initiator_status,
+                input_json,
+                counts_file,
+                cleanup_on_failure,
             } => {
+                let input_from_stdin = input_json.as_deref() == Some(Path::new("-"));
+                let input = match input_json.as_deref() {
+                    Some(path) => read_finalize_input(path, env.stdin.is_some())?,
+                    None => FinalizeInput::default(),
+                };
+                let counts = match counts_file.as_deref() {
+                    Some(path) => read_counts_file(path)?,
+                    None => input.counts,
+                };
+                let verdict = verdict.or(input.verdict).ok_or_else(|| {
+                    anyhow::anyhow!("--verdict is required (or set `verdict` in --input-json)")
+                })?;
+                let template = template
+                    .map(|p| {
+                        std::fs::read_to_string(&p)
+                            .with_context(|| format!("read template file {}", p.display()))
+                    })
+                    .transpose()?;
                 let report_markdown = match report_file {
-                    Some(p) => std::fs::read_to_string(&p)
-                        .with_context(|| format!("read report file {}", p.display()))?,
-                    None => read_stdin_to_string().context("read report markdown from stdin")?,
+                    _ if no_report => None,
+                    Some(p) => Some(
+                        std::fs::read_to_string(&p)
+                            .with_context(|| format!("read report file {}", p.display()))?,
+                    ),
+                    None => match input.report_markdown {
+                        Some(markdown) => Some(markdown),
+                        None if input_from_stdin => {
+                            return Err(anyhow::anyhow!(
+                                "--input-json - consumed stdin; set `report_markdown`, pass --report-file, or use --no-report"
+                            ));
+                        }
+                        None => Some(
+                            read_stdin_to_string(env.stdin.is_some())
+                                .context("read report markdown from stdin")?,
+                        ),
+                    },
                 };
 
                 let reviewer_id =
-                    require_arg_or_env(reviewer_id, use_env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
                 let session_id =
-                    require_arg_or_env(session_id, use_env, "MPCR_SESSION_ID", "--session-id")?;
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
                 let res = finalize_review(FinalizeReviewParams {
-                    session: SessionLocator::new(resolved.session_dir),
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
                     reviewer_id,
                     session_id,
                     verdict,
                     counts: SeverityCounts {
-                        blocker,
-                        major,
-                        minor,
-                        nit,
+                        blocker: blocker.or(counts.blocker).map_or(0, std::convert::identity),
+                        major: major.or(counts.major).map_or(0, std::convert::identity),
+                        minor: minor.or(counts.minor).map_or(0, std::convert::identity),
+                        nit: nit.or(counts.nit).map_or(0, std::convert::identity),
                     },
                     report_markdown,
                     now,
+                    update_if_finished,
+                    report_ext,
+                    allow_empty_report,
+                    initiator_status,
+                    template,
+                    cleanup_on_failure,
                 })?;
-                write_result(json, &res)?;
+                if !matches!(print_path, Some(PrintPathMode::Only)) {
+                    write_result(json, &res)?;
+                }
+                if let (Some(_), Some(report_path)) = (print_path, &res.report_path) {
+                    let path =
+                        std::path::absolute(report_path).context("resolve absolute report path")?;
+                    println!("{}", path.display());
+                }
             }
 
             ReviewerCommands::Note {
@@ -897,16 +2564,28 @@ fn run() -> anyhow::Result<()> {
                 session_id,
                 note_type,
                 content,
+                content_stdin,
                 content_json,
+                dedupe,
+                max_notes,
+                archive_pruned_notes,
+                tag,
+                severity,
             } => {
                 let reviewer_id =
-                    require_arg_or_env(reviewer_id, use_env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
                 let session_id =
-                    require_arg_or_env(session_id, use_env, "MPCR_SESSION_ID", "--session-id")?;
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
-                let content = parse_content(content_json, &content)?;
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let content =
+                    read_note_content(content, content_stdin, content_json, env.stdin.is_some())?;
                 append_note(AppendNoteParams {
-                    session: SessionLocator::new(resolved.session_dir),
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
                     reviewer_id: reviewer_id.clone(),
                     session_id,
                     role: NoteRole::Reviewer,
@@ -914,9 +2593,75 @@ fn run() -> anyhow::Result<()> {
                     content,
                     now,
                     lock_owner: reviewer_id,
+                    dedupe,
+                    max_notes: max_notes.map(usize::try_from).transpose()?,
+                    archive_pruned: archive_pruned_notes,
+                    tags: tag,
+                    severity,
+                    initiator_status: None,
                 })?;
                 write_ok(json)?;
             }
+            ReviewerCommands::AmendCounts {
+                session,
+                reviewer_id,
+                session_id,
+                blocker,
+                major,
+                minor,
+                nit,
+            } => {
+                let reviewer_id =
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                let session_id =
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let res = amend_counts(&AmendCountsParams {
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
+                    reviewer_id,
+                    session_id,
+                    counts: SeverityCounts {
+                        blocker,
+                        major,
+                        minor,
+                        nit,
+                    },
+                    now,
+                })?;
+                write_result(json, &res)?;
+            }
+
+            ReviewerCommands::NoteTypes => {
+                write_result(
+                    json,
+                    &NoteTypesResult {
+                        note_types: note_type_catalog(),
+                    },
+                )?;
+            }
+
+            ReviewerCommands::List {
+                session,
+                reviewer_id,
+            } => {
+                let reviewer_id =
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let session = SessionLocator::new(resolved.session_dir);
+                let session_data = if session.session_file().exists() {
+                    Some(load_session(&session)?)
+                } else {
+                    None
+                };
+                let res =
+                    list_reviewer_reviews(session_data.as_ref(), &session, &reviewer_id, now)?;
+                write_result(json, &res)?;
+            }
         },
 
         Commands::Applicator { command } => match command {
@@ -928,16 +2673,21 @@ fn run() -> anyhow::Result<()> {
                 lock_owner,
             } => {
                 let reviewer_id =
-                    require_arg_or_env(reviewer_id, use_env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
                 let session_id =
-                    require_arg_or_env(session_id, use_env, "MPCR_SESSION_ID", "--session-id")?;
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
                 let lock_owner = match lock_owner {
                     Some(lock_owner) => lock_owner,
                     None => id::random_id8()?,
                 };
                 let params = SetInitiatorStatusParams {
-                    session: SessionLocator::new(resolved.session_dir),
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
                     reviewer_id,
                     session_id,
                     initiator_status,
@@ -948,27 +2698,72 @@ fn run() -> anyhow::Result<()> {
                 write_ok(json)?;
             }
 
+            ApplicatorCommands::DeclineAll {
+                session,
+                reviewer_id,
+                session_id,
+                reason,
+                lock_owner,
+            } => {
+                let reviewer_id =
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                let session_id =
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let lock_owner = match lock_owner {
+                    Some(lock_owner) => lock_owner,
+                    None => id::random_id8()?,
+                };
+                decline_all(&DeclineAllParams {
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
+                    reviewer_id,
+                    session_id,
+                    reason,
+                    now,
+                    lock_owner,
+                })?;
+                write_ok(json)?;
+            }
+
             ApplicatorCommands::Note {
                 session,
                 reviewer_id,
                 session_id,
                 note_type,
                 content,
+                content_stdin,
                 content_json,
+                dedupe,
+                max_notes,
+                archive_pruned_notes,
+                tag,
+                severity,
+                set_initiator_status,
                 lock_owner,
             } => {
                 let reviewer_id =
-                    require_arg_or_env(reviewer_id, use_env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
+                    require_arg_or_env(reviewer_id, env, "MPCR_REVIEWER_ID", "--reviewer-id")?;
                 let session_id =
-                    require_arg_or_env(session_id, use_env, "MPCR_SESSION_ID", "--session-id")?;
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
-                let content = parse_content(content_json, &content)?;
+                    require_arg_or_env(session_id, env, "MPCR_SESSION_ID", "--session-id")?;
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let content =
+                    read_note_content(content, content_stdin, content_json, env.stdin.is_some())?;
                 let lock_owner = match lock_owner {
                     Some(lock_owner) => lock_owner,
                     None => id::random_id8()?,
                 };
                 append_note(AppendNoteParams {
-                    session: SessionLocator::new(resolved.session_dir),
+                    session: write_locator(
+                        resolved.session_dir,
+                        tmp_in.as_deref(),
+                        fs_lock,
+                        warnings,
+                    ),
                     reviewer_id,
                     session_id,
                     role: NoteRole::Applicator,
@@ -976,38 +2771,179 @@ fn run() -> anyhow::Result<()> {
                     content,
                     now,
                     lock_owner,
+                    dedupe,
+                    max_notes: max_notes.map(usize::try_from).transpose()?,
+                    archive_pruned: archive_pruned_notes,
+                    tags: tag,
+                    severity,
+                    initiator_status: set_initiator_status,
                 })?;
                 write_ok(json)?;
             }
 
+            ApplicatorCommands::NoteTypes => {
+                write_result(
+                    json,
+                    &NoteTypesResult {
+                        note_types: note_type_catalog(),
+                    },
+                )?;
+            }
+
+            ApplicatorCommands::Summary { session } => {
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let session = load_session(&SessionLocator::new(resolved.session_dir))?;
+                write_result(json, &summarize_for_applicator(&session))?;
+            }
+
             ApplicatorCommands::Wait {
                 session,
                 target_ref,
                 session_id,
+                any,
+                poll_initial_secs,
+                poll_max_secs,
             } => {
-                let target_ref = target_ref.or_else(|| opt_env_string(use_env, "MPCR_TARGET_REF"));
-                let session_id = session_id.or_else(|| opt_env_string(use_env, "MPCR_SESSION_ID"));
-                let resolved = resolve_session_input(use_env, &session, now.date())?;
-                wait_for_reviews(
+                let backoff = PollBackoff::from_secs(
+                    poll_initial_secs
+                        .or(config.poll_initial_secs)
+                        .map_or(PollBackoff::DEFAULT_INITIAL_SECS, std::convert::identity),
+                    poll_max_secs
+                        .or(config.poll_max_secs)
+                        .map_or(PollBackoff::DEFAULT_MAX_SECS, std::convert::identity),
+                )?;
+                let target_ref = target_ref.or_else(|| opt_env_string(env, "MPCR_TARGET_REF"));
+                let session_id = session_id.or_else(|| opt_env_string(env, "MPCR_SESSION_ID"));
+                let resolved = resolve_session_input(env, config, &session, now)?;
+                let first = wait_for_reviews(
                     &resolved.session_dir,
                     target_ref.as_deref(),
                     session_id.as_deref(),
+                    any,
+                    backoff,
                 )?;
-                write_ok(json)?;
+                if any {
+                    write_result(
+                        json,
+                        &WaitAnyResult {
+                            ok: true,
+                            reviewer_id: first.as_ref().map(|r| r.reviewer_id.clone()),
+                            session_id: first.map(|r| r.session_id),
+                        },
+                    )?;
+                } else {
+                    write_ok(json)?;
+                }
             }
         },
+
+        Commands::Batch {
+            session,
+            file,
+            lock_owner,
+        } => {
+            let input = std::fs::read_to_string(&file)
+                .with_context(|| format!("read batch file {}", file.display()))?;
+            let ops = parse_batch_ops(&input)?;
+            let resolved = resolve_session_input(env, config, &session, now)?;
+            let lock_owner = match lock_owner {
+                Some(lock_owner) => lock_owner,
+                None => id::random_id8()?,
+            };
+            let res = apply_batch(BatchParams {
+                session: write_locator(resolved.session_dir, tmp_in.as_deref(), fs_lock, warnings),
+                ops,
+                now,
+                lock_owner,
+            })?;
+            write_result(json, &res)?;
+        }
+        Commands::Version => {
+            write_result(
+                json,
+                &VersionInfo {
+                    name: env!("CARGO_PKG_NAME"),
+                    version: env!("CARGO_PKG_VERSION"),
+                    schema_version: CURRENT_SCHEMA_VERSION,
+                    git_sha: option_env!("MPCR_GIT_SHA"),
+                },
+            )?;
+        }
     }
 
-    Ok(())
+    Ok(())
+}
+
+fn resolve_session_input(
+    env: EnvSource<'_>,
+    config: &ConfigDefaults,
+    args: &SessionDirArgs,
+    now: OffsetDateTime,
+) -> anyhow::Result<ResolvedSessionInput> {
+    let cwd = std::env::current_dir().context("get cwd")?;
+    resolve_session_input_from_cwd(env, args, now, &cwd, config)
+}
+
+/// Defaults loaded from `--config`. Only these keys are accepted; each applies only when neither
+/// the matching flag nor an opted-in `MPCR_*` variable is set.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigDefaults {
+    /// Directory holding `YYYY-MM-DD` session dirs (relative paths resolve against the repo
+    /// root); replaces `<repo_root>/.local/reports/code_reviews` when `--session-dir` is unset.
+    reports_base: Option<PathBuf>,
+    /// Default for `--tz`.
+    tz: Option<String>,
+    /// Default for `applicator wait --poll-initial-secs`.
+    poll_initial_secs: Option<f64>,
+    /// Default for `applicator wait --poll-max-secs`.
+    poll_max_secs: Option<f64>,
+    /// Turn on `--fs-lock` (the flag can only turn it on, not off).
+    fs_lock: Option<bool>,
+}
+
+impl ConfigDefaults {
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("read config file {}", path.display()))?;
+        serde_json::from_str(&raw).with_context(|| format!("parse config file {}", path.display()))
+    }
 }
 
-fn resolve_session_input(
+/// Where `MPCR_*` fallbacks come from: the environment under `--use-env`, else the
+/// `--stdin-json` context (when given).
+#[derive(Debug, Default, Clone, Copy)]
+struct EnvSource<'a> {
     use_env: bool,
-    args: &SessionDirArgs,
-    default_date: Date,
-) -> anyhow::Result<ResolvedSessionInput> {
-    let cwd = std::env::current_dir().context("get cwd")?;
-    resolve_session_input_from_cwd(use_env, args, default_date, &cwd)
+    stdin: Option<&'a StdinContext>,
+}
+
+/// Defaults read from stdin by `--stdin-json`, standing in for the matching `MPCR_*` variables.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StdinContext {
+    session_dir: Option<String>,
+    reviewer_id: Option<String>,
+    session_id: Option<String>,
+    target_ref: Option<String>,
+}
+
+impl StdinContext {
+    fn load() -> anyhow::Result<Self> {
+        let raw = read_stdin_to_string(false).context("read --stdin-json context")?;
+        serde_json::from_str(&raw).context("parse --stdin-json context")
+    }
+
+    /// Value standing in for the `MPCR_*` variable `env_key`.
+    fn value(&self, env_key: &str) -> Option<&str> {
+        match env_key {
+            "MPCR_SESSION_DIR" => self.session_dir.as_deref(),
+            "MPCR_REVIEWER_ID" => self.reviewer_id.as_deref(),
+            "MPCR_SESSION_ID" => self.session_id.as_deref(),
+            "MPCR_TARGET_REF" => self.target_ref.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 fn discover_repo_root(start: &Path) -> Option<PathBuf> {
@@ -1022,22 +2958,34 @@ fn discover_repo_root(start: &Path) -> Option<PathBuf> {
 }
 
 fn resolve_session_input_from_cwd(
-    use_env: bool,
+    env: EnvSource<'_>,
     args: &SessionDirArgs,
-    default_date: Date,
+    now: OffsetDateTime,
     cwd: &Path,
+    config: &ConfigDefaults,
 ) -> anyhow::Result<ResolvedSessionInput> {
     let repo_root = args
         .repo_root
         .clone()
-        .or_else(|| opt_env_pathbuf(use_env, "MPCR_REPO_ROOT"))
+        .or_else(|| opt_env_pathbuf(env, "MPCR_REPO_ROOT"))
         .or_else(|| discover_repo_root(cwd))
         .map_or_else(|| cwd.to_path_buf(), std::convert::identity);
     let date_raw = args
         .date
         .as_deref()
         .map(std::string::ToString::to_string)
-        .or_else(|| opt_env_string(use_env, "MPCR_DATE"));
+        .or_else(|| opt_env_string(env, "MPCR_DATE"));
+    let tz_raw = args
+        .tz
+        .clone()
+        .or_else(|| opt_env_string(env, "MPCR_TZ"))
+        .or_else(|| config.tz.clone());
+    let tz = tz_raw
+        .as_deref()
+        .map(parse_utc_offset)
+        .transpose()?
+        .map_or(UtcOffset::UTC, std::convert::identity);
+    let default_date = now.to_offset(tz).date();
     let session_date = match date_raw.as_deref() {
         Some(date) => parse_date_ymd(date)?,
         None => default_date,
@@ -1045,7 +2993,13 @@ fn resolve_session_input_from_cwd(
     let session_dir = args
         .session_dir
         .clone()
-        .or_else(|| opt_env_pathbuf(use_env, "MPCR_SESSION_DIR"))
+        .or_else(|| opt_env_pathbuf(env, "MPCR_SESSION_DIR"))
+        .or_else(|| {
+            config
+                .reports_base
+                .as_ref()
+                .map(|base| repo_root.join(base).join(session_date.to_string()))
+        })
         .map_or_else(
             || mpcr::paths::session_paths(&repo_root, session_date).session_dir,
             std::convert::identity,
@@ -1055,6 +3009,7 @@ fn resolve_session_input_from_cwd(
         session_dir,
         repo_root,
         session_date,
+        tz,
     })
 }
 
@@ -1082,6 +3037,64 @@ fn parse_date_ymd(s: &str) -> anyhow::Result<Date> {
     Date::from_calendar_date(year, month, day).context("invalid calendar date")
 }
 
+/// Parse a simple duration (`<digits><unit>`, unit one of `s`, `m`, `h`, `d`) into seconds.
+fn parse_duration_secs(s: &str) -> anyhow::Result<u64> {
+    let Some(unit) = s.chars().last() else {
+        return Err(anyhow::anyhow!("invalid duration: empty"));
+    };
+    let multiplier: u64 = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        _ => {
+            return Err(anyhow::anyhow!(
+                "invalid duration: {s} (expected <N>s, <N>m, <N>h, or <N>d)"
+            ))
+        }
+    };
+    let (digits, _) = s.split_at(s.len() - unit.len_utf8());
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(anyhow::anyhow!(
+            "invalid duration: {s} (expected <N>s, <N>m, <N>h, or <N>d)"
+        ));
+    }
+    let value: u64 = digits.parse().context("parse duration value")?;
+    value
+        .checked_mul(multiplier)
+        .ok_or_else(|| anyhow::anyhow!("invalid duration: {s} is too large"))
+}
+
+fn parse_utc_offset(s: &str) -> anyhow::Result<UtcOffset> {
+    if s.eq_ignore_ascii_case("UTC") || s.eq_ignore_ascii_case("Z") {
+        return Ok(UtcOffset::UTC);
+    }
+    let (negative, rest) = if let Some(rest) = s.strip_prefix('+') {
+        (false, rest)
+    } else if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        return Err(anyhow::anyhow!(
+            "invalid offset: expected +HH[:MM] or -HH[:MM]"
+        ));
+    };
+    let (hours_raw, minutes_raw) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None => (rest, "0"),
+    };
+    let hours: i8 = hours_raw.parse().context("parse offset hours")?;
+    let minutes: i8 = minutes_raw.parse().context("parse offset minutes")?;
+    if !(0..=23).contains(&hours) || !(0..=59).contains(&minutes) {
+        return Err(anyhow::anyhow!("invalid offset: out of range: {s}"));
+    }
+    let (hours, minutes) = if negative {
+        (-hours, -minutes)
+    } else {
+        (hours, minutes)
+    };
+    UtcOffset::from_hms(hours, minutes, 0).context("invalid offset")
+}
+
 fn parse_content(as_json: bool, raw: &str) -> anyhow::Result<Value> {
     if as_json {
         serde_json::from_str(raw).context("parse --content as JSON")
@@ -1090,7 +3103,99 @@ fn parse_content(as_json: bool, raw: &str) -> anyhow::Result<Value> {
     }
 }
 
-fn read_stdin_to_string() -> anyhow::Result<String> {
+fn read_note_content(
+    content: Option<String>,
+    from_stdin: bool,
+    as_json: bool,
+    stdin_consumed: bool,
+) -> anyhow::Result<Value> {
+    let raw = match content {
+        Some(raw) if !from_stdin => raw,
+        _ => read_stdin_to_string(stdin_consumed).context("read note content from stdin")?,
+    };
+    parse_content(as_json, &raw)
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// `reviewer finalize --input-json` object; explicit flags take precedence over each field.
+struct FinalizeInput {
+    verdict: Option<ReviewVerdict>,
+    #[serde(default)]
+    counts: FinalizeInputCounts,
+    report_markdown: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FinalizeInputCounts {
+    blocker: Option<u64>,
+    major: Option<u64>,
+    minor: Option<u64>,
+    nit: Option<u64>,
+}
+
+/// Read a [`FinalizeInput`] from `path`, or from stdin when `path` is `-`.
+fn read_finalize_input(path: &Path, stdin_consumed: bool) -> anyhow::Result<FinalizeInput> {
+    let raw = if path == Path::new("-") {
+        read_stdin_to_string(stdin_consumed).context("read --input-json from stdin")?
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("read --input-json file {}", path.display()))?
+    };
+    serde_json::from_str(&raw).context("parse --input-json")
+}
+
+/// Read `--counts-file`: a JSON object of severity counts (missing keys default to 0).
+fn read_counts_file(path: &Path) -> anyhow::Result<FinalizeInputCounts> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("read --counts-file {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("parse --counts-file {}", path.display()))
+}
+
+/// Read `--target-ref-file`: the file's contents with surrounding whitespace trimmed.
+fn read_target_ref_file(path: &Path) -> anyhow::Result<String> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("read --target-ref-file {}", path.display()))?;
+    let target_ref = raw.trim();
+    if target_ref.is_empty() {
+        return Err(anyhow::anyhow!(
+            "--target-ref-file {} is empty",
+            path.display()
+        ));
+    }
+    Ok(target_ref.to_string())
+}
+
+fn read_reviewer_id_file(path: &Path) -> anyhow::Result<Vec<String>> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("read --reviewer-id-file {}", path.display()))?;
+    let mut ids = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let id = line.trim();
+        if id.is_empty() {
+            continue;
+        }
+        validate_id8(id, "reviewer_id")
+            .with_context(|| format!("{}:{}: {id:?}", path.display(), idx + 1))?;
+        ids.push(id.to_string());
+    }
+    if ids.is_empty() {
+        return Err(anyhow::anyhow!(
+            "--reviewer-id-file {} lists no reviewer ids",
+            path.display()
+        ));
+    }
+    Ok(ids)
+}
+
+/// Read all of stdin; `stdin_consumed` means `--stdin-json` already read it.
+fn read_stdin_to_string(stdin_consumed: bool) -> anyhow::Result<String> {
+    if stdin_consumed {
+        return Err(anyhow::anyhow!(
+            "stdin was already consumed by --stdin-json; pass this input via a file instead"
+        ));
+    }
     let mut buf = String::new();
     std::io::stdin()
         .read_to_string(&mut buf)
@@ -1098,6 +3203,24 @@ fn read_stdin_to_string() -> anyhow::Result<String> {
     Ok(buf)
 }
 
+fn run_shell_command(cmd: &str) -> anyhow::Result<std::process::ExitStatus> {
+    #[cfg(windows)]
+    let mut command = {
+        let mut c = std::process::Command::new("cmd");
+        c.arg("/C").arg(cmd);
+        c
+    };
+    #[cfg(not(windows))]
+    let mut command = {
+        let mut c = std::process::Command::new("sh");
+        c.arg("-c").arg(cmd);
+        c
+    };
+    command
+        .status()
+        .with_context(|| format!("run command `{cmd}`"))
+}
+
 fn write_ok(json: bool) -> anyhow::Result<()> {
     if json {
         write_result(true, &OkResult { ok: true })
@@ -1115,6 +3238,27 @@ fn write_json<T: Serialize>(value: &T) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Print `_session.json` as generic JSON, bypassing the typed schema.
+///
+/// Text that is not JSON at all is echoed verbatim before returning the parse error.
+fn show_raw_session(locator: &SessionLocator) -> anyhow::Result<()> {
+    let path = locator.session_file();
+    let text = std::fs::read_to_string(&path)
+        .with_context(|| format!("read session file {}", path.display()))?;
+    match serde_json::from_str::<serde_json::Value>(&text) {
+        Ok(value) => write_json(&value),
+        Err(err) => {
+            let mut stdout = std::io::stdout().lock();
+            stdout.write_all(text.as_bytes()).context("write stdout")?;
+            stdout.flush().context("flush stdout")?;
+            Err(anyhow::anyhow!(
+                "session file {} is not valid JSON: {err}",
+                path.display()
+            ))
+        }
+    }
+}
+
 fn write_env_sh(pairs: &[(&str, &str)]) -> anyhow::Result<()> {
     let mut stdout = std::io::stdout();
     for (key, value) in pairs {
@@ -1167,14 +3311,106 @@ fn write_result<T: Serialize>(json: bool, value: &T) -> anyhow::Result<()> {
     }
 }
 
+impl ReportsFilterArgs {
+    /// Build [`ReportsFilters`], rejecting view-specific flags used outside their view (`None`
+    /// means no view, e.g. `session export`, where every flag applies).
+    fn into_filters(self, view: Option<ReportsView>) -> anyhow::Result<ReportsFilters> {
+        if self.only_actionable && view.is_some_and(|view| view != ReportsView::Closed) {
+            return Err(anyhow::anyhow!(
+                "--only-actionable is only supported by `session reports closed`"
+            ));
+        }
+        let open_older_than_secs = match self.open_older_than.as_deref() {
+            Some(raw) => {
+                if view.is_some_and(|view| view != ReportsView::Open) {
+                    return Err(anyhow::anyhow!(
+                        "--open-older-than is only supported by `session reports open`"
+                    ));
+                }
+                Some(parse_duration_secs(raw)?)
+            }
+            None => None,
+        };
+        let (reviewer_id, reviewer_ids) = match self.reviewer_id_file.as_deref() {
+            Some(path) => {
+                let mut ids = read_reviewer_id_file(path)?;
+                ids.extend(self.reviewer_id);
+                (None, ids)
+            }
+            None => (self.reviewer_id, Vec::new()),
+        };
+        let changed_since = match self.changed_since.as_deref() {
+            Some(path) => Some(updated_at_baseline(&load_session_snapshot(path)?)),
+            None => None,
+        };
+        let sla_breach_secs = match self.sla_breach.as_deref() {
+            Some(raw) => {
+                if view.is_some_and(|view| view != ReportsView::Open) {
+                    return Err(anyhow::anyhow!(
+                        "--sla-breach is only supported by `session reports open`"
+                    ));
+                }
+                Some(parse_duration_secs(raw)?)
+            }
+            None => None,
+        };
+        let note_since_secs = self
+            .note_since
+            .as_deref()
+            .map(parse_duration_secs)
+            .transpose()?;
+
+        Ok(ReportsFilters {
+            target_ref: self.target_ref,
+            session_id: self.session_id,
+            reviewer_id,
+            reviewer_ids,
+            exclude_reviewer_ids: self.exclude_reviewer_id,
+            reviewer_statuses: self.reviewer_status,
+            initiator_statuses: self.initiator_status,
+            verdicts: self.verdict,
+            phases: self.phase,
+            phase_at_least: self.phase_at_least,
+            only_with_report: self.only_with_report,
+            only_with_notes: self.only_with_notes,
+            min_severity: self.min_severity,
+            open_older_than_secs,
+            sla_breach_secs,
+            target_ref_glob: self.target_ref_glob,
+            finished_after: self.finished_after,
+            finished_before: self.finished_before,
+            report_grep: self.report_grep,
+            report_grep_ignore_case: self.ignore_case,
+            only_actionable: self.only_actionable,
+            note_tags: self.note_tag,
+            note_min_severity: self.note_min_severity,
+            note_since_secs,
+            changed_since,
+            review_key: self.review_key,
+            unique: self.unique,
+        })
+    }
+}
+
+/// Point repo-relative `report_file` resolution at `base` instead of the stored `repo_root`
+/// (in memory only; legacy filename-only reports still resolve against the session dir).
+fn rebase_report_paths(session_data: &mut SessionFile, base: Option<&Path>) {
+    if let Some(base) = base {
+        session_data.repo_root = base.to_string_lossy().to_string();
+    }
+}
+
+#[allow(clippy::too_many_lines)]
 fn handle_reports(
-    use_env: bool,
+    env: EnvSource<'_>,
+    config: &ConfigDefaults,
     json: bool,
-    default_date: Date,
+    now: OffsetDateTime,
     view: ReportsView,
     args: ReportsArgs,
+    warnings: &mut Warnings,
 ) -> anyhow::Result<()> {
-    let resolved = resolve_session_input(use_env, &args.session, default_date)?;
+    let resolved = resolve_session_input(env, config, &args.session, now)?;
     let session = SessionLocator::new(resolved.session_dir);
 
     if session.session_dir().exists() && !session.session_dir().is_dir() {
@@ -1184,24 +3420,68 @@ fn handle_reports(
         ));
     }
 
-    let filters = ReportsFilters {
-        target_ref: args.target_ref,
-        session_id: args.session_id,
-        reviewer_id: args.reviewer_id,
-        reviewer_statuses: args.reviewer_status,
-        initiator_statuses: args.initiator_status,
-        verdicts: args.verdict,
-        phases: args.phase,
-        only_with_report: args.only_with_report,
-        only_with_notes: args.only_with_notes,
-    };
+    if args.verdict_summary && view != ReportsView::Closed {
+        return Err(anyhow::anyhow!(
+            "--verdict-summary is only supported by `session reports closed`"
+        ));
+    }
+    if args.local_time && json {
+        return Err(anyhow::anyhow!(
+            "--local-time only affects human output; --json timestamps stay UTC"
+        ));
+    }
+    if args.pretty_notes && json {
+        return Err(anyhow::anyhow!(
+            "--pretty-notes only affects human output; drop --json"
+        ));
+    }
+
+    let include_notes = args.include_notes
+        || args.filters.only_with_notes
+        || args.flatten_notes
+        || args.pretty_notes;
+    let filters = args.filters.into_filters(Some(view))?;
     let options = ReportsOptions {
-        include_notes: args.include_notes || args.only_with_notes,
+        include_notes,
         include_report_contents: args.include_report_contents,
+        include_report_size: args.include_report_size,
+        count_by_phase: args.count_by_phase,
+        dedupe_notes: args.dedupe_notes,
+        verdict_summary: args.verdict_summary,
+        include_age: args.include_age,
+        include_history: args.include_history,
+        redact_notes: args.redact,
+        note_author_counts: args.note_author_count,
     };
 
-    if !session.session_file().exists() {
-        let result = ReportsResult {
+    let mut result = if let Some(snapshot) = args.as_of.as_deref() {
+        let mut session_data = load_session_snapshot(snapshot)?;
+        rebase_report_paths(&mut session_data, args.report_path_base.as_deref());
+        let mut result = collect_reports_with_warnings(
+            &session_data,
+            &session,
+            view,
+            filters,
+            options,
+            now,
+            warnings,
+        )?;
+        result.session_file = snapshot.to_string_lossy().to_string();
+        result
+    } else if session.session_file().exists() {
+        let mut session_data = load_session(&session)?;
+        rebase_report_paths(&mut session_data, args.report_path_base.as_deref());
+        collect_reports_with_warnings(
+            &session_data,
+            &session,
+            view,
+            filters,
+            options,
+            now,
+            warnings,
+        )?
+    } else {
+        ReportsResult {
             session_dir: session.session_dir().to_string_lossy().to_string(),
             session_file: session.session_file().to_string_lossy().to_string(),
             view,
@@ -1210,55 +3490,312 @@ fn handle_reports(
             total_reviews: 0,
             matching_reviews: 0,
             reviews: Vec::new(),
+            phase_counts: None,
+            verdict_summary: None,
+            lock_state: None,
+        }
+    };
+
+    if args.include_lock_state {
+        result.lock_state = Some(lock::read_lock_state(
+            session.session_dir(),
+            std::time::SystemTime::now(),
+        ));
+    }
+    if args.local_time {
+        localize_review_timestamps(&mut result, resolved.tz);
+    }
+
+    if args.tsv {
+        write_reports_tsv(&result)?;
+    } else if args.flatten_notes {
+        write_result(json, &flatten_report_notes(&result))?;
+    } else if args.json_lines {
+        write_reports_json_lines(&result, args.json_lines_header)?;
+    } else if args.single {
+        let [review] = result.reviews.as_slice() else {
+            return Err(anyhow::anyhow!(
+                "--single expected exactly one matching review, found {}",
+                result.reviews.len()
+            ));
+        };
+        write_result(json, review)?;
+    } else if args.summary_only {
+        write_result(json, &summarize_reports(&result))?;
+    } else if args.distinct_reviewers {
+        let reviewer_ids: std::collections::BTreeSet<&str> = result
+            .reviews
+            .iter()
+            .map(|review| review.reviewer_id.as_str())
+            .collect();
+        write_result(
+            json,
+            &serde_json::json!({ "count": reviewer_ids.len(), "reviewer_ids": reviewer_ids }),
+        )?;
+    } else if args.target_refs_only {
+        let target_refs: std::collections::BTreeSet<&str> = result
+            .reviews
+            .iter()
+            .map(|review| review.target_ref.as_str())
+            .collect();
+        if json {
+            write_json(&serde_json::json!({ "target_refs": target_refs }))?;
+        } else {
+            let mut stdout = std::io::stdout().lock();
+            for target_ref in target_refs {
+                writeln!(stdout, "{target_ref}").context("write stdout")?;
+            }
+            stdout.flush().context("flush stdout")?;
+        }
+    } else if args.pretty_notes {
+        write_reports_pretty_notes(&result)?;
+    } else if let Some(pointer) = args.json_pointer.as_deref() {
+        let value = serde_json::to_value(&result).context("serialize reports result")?;
+        let selected = value
+            .pointer(pointer)
+            .ok_or_else(|| anyhow::anyhow!("--json-pointer {pointer:?} does not resolve"))?;
+        write_result(json, selected)?;
+    } else {
+        write_result(json, &result)?;
+    }
+
+    if args.fail_if_empty && result.matching_reviews == 0 {
+        return Err(anyhow::anyhow!(
+            "no reviews matched (--fail-if-empty); output was still written"
+        ));
+    }
+    Ok(())
+}
+
+/// Rewrite displayed review and note timestamps at `offset`; unparseable values are left as-is.
+fn localize_review_timestamps(result: &mut ReportsResult, offset: UtcOffset) {
+    let localize = |raw: &mut String| {
+        if let Ok(local) = parse_ts(raw).and_then(|ts| {
+            ts.to_offset(offset)
+                .format(&time::format_description::well_known::Rfc3339)
+                .context("format timestamp")
+        }) {
+            *raw = local;
+        }
+    };
+    for review in &mut result.reviews {
+        localize(&mut review.started_at);
+        localize(&mut review.updated_at);
+        review
+            .finished_at
+            .iter_mut()
+            .chain(review.eta.iter_mut())
+            .for_each(localize);
+        review
+            .notes
+            .iter_mut()
+            .flatten()
+            .for_each(|note| localize(&mut note.timestamp));
+    }
+}
+
+#[derive(Serialize)]
+struct ReportsHeader<'a> {
+    session_dir: &'a str,
+    session_file: &'a str,
+    view: ReportsView,
+    filters: &'a ReportsFilters,
+    options: ReportsOptions,
+    total_reviews: usize,
+    matching_reviews: usize,
+}
+
+fn write_reports_json_lines(result: &ReportsResult, header: bool) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    if header {
+        let header = ReportsHeader {
+            session_dir: &result.session_dir,
+            session_file: &result.session_file,
+            view: result.view,
+            filters: &result.filters,
+            options: result.options,
+            total_reviews: result.total_reviews,
+            matching_reviews: result.matching_reviews,
         };
-        return write_result(json, &result);
+        write_json_line(&mut stdout, &header)?;
+    }
+    for review in &result.reviews {
+        write_json_line(&mut stdout, review)?;
+    }
+    stdout.flush().context("flush stdout")
+}
+
+fn write_reports_tsv(result: &ReportsResult) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{}", REPORT_TABLE_COLUMNS.join("\t")).context("write stdout")?;
+    for review in &result.reviews {
+        let row = review.table_row().map(|cell| tsv_escape(&cell));
+        writeln!(stdout, "{}", row.join("\t")).context("write stdout")?;
+    }
+    stdout.flush().context("flush stdout")
+}
+
+/// Write a listing for reading in a terminal: one header line per review, then each included
+/// note's role, type, and timestamp with its `SessionNote::content_text` indented below.
+fn write_reports_pretty_notes(result: &ReportsResult) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    for review in &result.reviews {
+        writeln!(
+            stdout,
+            "{} {} {} {}",
+            review.reviewer_id,
+            review.session_id,
+            review.target_ref,
+            wire_name(&review.status)
+        )
+        .context("write stdout")?;
+        for note in review.notes.iter().flatten() {
+            writeln!(
+                stdout,
+                "  - {} {} {}",
+                wire_name(&note.role),
+                wire_name(&note.note_type),
+                note.timestamp
+            )
+            .context("write stdout")?;
+            for line in note.content_text().lines() {
+                writeln!(stdout, "    {line}").context("write stdout")?;
+            }
+        }
+    }
+    stdout.flush().context("flush stdout")
+}
+
+/// Escape backslashes, tabs, and line breaks so each value stays within one TSV cell.
+fn tsv_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
     }
+    out
+}
 
-    let session_data = load_session(&session)?;
-    let result = collect_reports(&session_data, &session, view, filters, options);
-    write_result(json, &result)
+fn write_json_line<T: Serialize>(out: &mut impl Write, value: &T) -> anyhow::Result<()> {
+    serde_json::to_writer(&mut *out, value).context("serialize JSON")?;
+    out.write_all(b"\n").context("write newline")
 }
 
-fn opt_env_string(use_env: bool, key: &str) -> Option<String> {
-    if !use_env {
-        return None;
+fn opt_env_string(env: EnvSource<'_>, key: &str) -> Option<String> {
+    if !env.use_env {
+        return env.stdin.and_then(|ctx| ctx.value(key)).map(str::to_string);
     }
     std::env::var(key).ok()
 }
 
-fn opt_env_pathbuf(use_env: bool, key: &str) -> Option<PathBuf> {
-    if !use_env {
-        return None;
+fn opt_env_pathbuf(env: EnvSource<'_>, key: &str) -> Option<PathBuf> {
+    if !env.use_env {
+        return env.stdin.and_then(|ctx| ctx.value(key)).map(PathBuf::from);
     }
     std::env::var_os(key).map(PathBuf::from)
 }
 
 fn require_arg_or_env(
     value: Option<String>,
-    use_env: bool,
+    env: EnvSource<'_>,
     env_key: &str,
     arg_flag: &str,
 ) -> anyhow::Result<String> {
     value
-        .or_else(|| opt_env_string(use_env, env_key))
+        .or_else(|| opt_env_string(env, env_key))
         .ok_or_else(|| {
-            if use_env {
+            if env.use_env {
                 anyhow::anyhow!(
                     "missing {arg_flag}; pass {arg_flag} (or set {env_key} and pass --use-env)"
                 )
+            } else if env.stdin.is_some() {
+                let field = env_key
+                    .strip_prefix("MPCR_")
+                    .map_or_else(String::new, str::to_ascii_lowercase);
+                anyhow::anyhow!(
+                    "missing {arg_flag}; pass {arg_flag} (or set `{field}` in the --stdin-json object)"
+                )
             } else {
                 anyhow::anyhow!("missing {arg_flag}; pass {arg_flag}")
             }
         })
 }
 
+/// Which terminal review ended an `applicator wait --any`.
+#[derive(Debug, Serialize)]
+struct WaitAnyResult {
+    ok: bool,
+    reviewer_id: Option<String>,
+    session_id: Option<String>,
+}
+
+/// Polling backoff for `applicator wait`: sleep `initial`, doubling after each poll up to `max`.
+#[derive(Debug, Clone, Copy)]
+struct PollBackoff {
+    initial: std::time::Duration,
+    max: std::time::Duration,
+}
+
+impl PollBackoff {
+    const DEFAULT_INITIAL_SECS: f64 = 1.0;
+    const DEFAULT_MAX_SECS: f64 = 60.0;
+
+    fn from_secs(initial_secs: f64, max_secs: f64) -> anyhow::Result<Self> {
+        let parse = |flag: &str, secs: f64| {
+            if !(secs.is_finite() && secs > 0.0) {
+                return Err(anyhow::anyhow!(
+                    "{flag} must be a positive number of seconds"
+                ));
+            }
+            std::time::Duration::try_from_secs_f64(secs)
+                .map_err(|err| anyhow::anyhow!("invalid {flag}: {err}"))
+        };
+        let initial = parse("--poll-initial-secs", initial_secs)?;
+        let max = parse("--poll-max-secs", max_secs)?;
+        if initial > max {
+            return Err(anyhow::anyhow!(
+                "--poll-initial-secs ({initial_secs}) must not exceed --poll-max-secs ({max_secs})"
+            ));
+        }
+        Ok(Self { initial, max })
+    }
+}
+
+/// Locator for a command that writes `_session.json`, staging writes in `--tmp-in` when it is
+/// usable (see [`SessionLocator::with_tmp_dir`]) and locking with `--fs-lock`.
+fn write_locator(
+    session_dir: PathBuf,
+    tmp_in: Option<&Path>,
+    fs_lock: bool,
+    warnings: &mut Warnings,
+) -> SessionLocator {
+    let locator = SessionLocator::new(session_dir).with_fs_lock(fs_lock);
+    match tmp_in {
+        Some(dir) => locator.with_tmp_dir(dir, warnings),
+        None => locator,
+    }
+}
+
+/// Block until matching reviews are terminal.
+///
+/// By default this waits for *all* matching reviews. With `any`, it returns as soon as one matching
+/// review is terminal and reports it (the earliest `finished_at`, falling back to `updated_at`).
+/// Either way, it returns `None` immediately when there is nothing left to wait for. Non-blocking
+/// (observe-only) entries are ignored, so a finished observer never ends an `any` wait.
 fn wait_for_reviews(
     session_dir: &Path,
     target_ref: Option<&str>,
     session_id: Option<&str>,
-) -> anyhow::Result<()> {
-    let mut delay = std::time::Duration::from_secs(1);
-    let max_delay = std::time::Duration::from_secs(60);
+    any: bool,
+    backoff: PollBackoff,
+) -> anyhow::Result<Option<ReviewEntry>> {
+    let mut delay = backoff.initial;
+    let max_delay = backoff.max;
     let session = SessionLocator::new(session_dir.to_path_buf());
     let should_wait_for_session = target_ref.is_some() || session_id.is_some();
 
@@ -1272,7 +3809,7 @@ fn wait_for_reviews(
     loop {
         if !session.session_file().exists() {
             if !should_wait_for_session {
-                return Ok(());
+                return Ok(None);
             }
             std::thread::sleep(delay);
             delay = std::cmp::min(delay.saturating_mul(2), max_delay);
@@ -1282,26 +3819,29 @@ fn wait_for_reviews(
         let session_data = load_session(&session)
             .with_context(|| format!("read session file under {}", session_dir.display()))?;
 
-        let mut has_pending = false;
-        for r in session_data.reviews {
-            if let Some(tr) = target_ref {
-                if r.target_ref != tr {
-                    continue;
-                }
-            }
-            if let Some(sid) = session_id {
-                if r.session_id != sid {
-                    continue;
-                }
-            }
-            if !r.status.is_terminal() {
-                has_pending = true;
-                break;
+        let (terminal, pending): (Vec<ReviewEntry>, Vec<ReviewEntry>) = session_data
+            .reviews
+            .into_iter()
+            .filter(|r| target_ref.is_none_or(|tr| r.target_ref == tr))
+            .filter(|r| session_id.is_none_or(|sid| r.session_id == sid))
+            .filter(ReviewEntry::is_blocking)
+            .partition(|r| r.status.is_terminal());
+
+        if any {
+            let first = terminal.into_iter().min_by(|a, b| {
+                let key = |r: &ReviewEntry| {
+                    r.finished_at
+                        .as_deref()
+                        .map_or_else(|| r.updated_at.clone(), str::to_string)
+                };
+                key(a).cmp(&key(b))
+            });
+            if first.is_some() {
+                return Ok(first);
             }
         }
-
-        if !has_pending {
-            return Ok(());
+        if pending.is_empty() {
+            return Ok(None);
         }
 
         std::thread::sleep(delay);
@@ -1342,6 +3882,14 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn tsv_escape_keeps_values_in_one_cell() -> anyhow::Result<()> {
+        ensure!(tsv_escape("refs/heads/main") == "refs/heads/main");
+        ensure!(tsv_escape("a\tb\nc\rd") == "a\\tb\\nc\\rd");
+        ensure!(tsv_escape("back\\slash") == "back\\\\slash");
+        Ok(())
+    }
+
     #[test]
     fn wait_for_reviews_returns_when_terminal() -> anyhow::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -1358,10 +3906,13 @@ mod tests {
             updated_at: "2026-01-11T01:00:00Z".to_string(),
             finished_at: Some("2026-01-11T02:00:00Z".to_string()),
             current_phase: None,
+            eta: None,
+            blocking: None,
             verdict: Some(ReviewVerdict::Approve),
             counts: SeverityCounts::zero(),
             report_file: Some("report.md".to_string()),
             notes: Vec::new(),
+            status_history: Vec::new(),
         };
         let session = SessionFile {
             schema_version: "1.0.0".to_string(),
@@ -1373,7 +3924,85 @@ mod tests {
         let body = serde_json::to_string_pretty(&session)? + "\n";
         fs::write(session_dir.join("_session.json"), body)?;
 
-        wait_for_reviews(&session_dir, None, None)?;
+        let backoff = PollBackoff::from_secs(0.01, 0.05)?;
+        ensure!(wait_for_reviews(&session_dir, None, None, false, backoff)?.is_none());
+        let first = wait_for_reviews(&session_dir, None, None, true, backoff)?;
+        ensure!(first.is_some_and(|r| r.reviewer_id == "deadbeef"));
+        Ok(())
+    }
+
+    #[test]
+    fn wait_for_reviews_any_ignores_finished_observers() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let session_dir = dir.path().join("session");
+        fs::create_dir_all(&session_dir)?;
+        let observer = ReviewEntry {
+            reviewer_id: "cafebabe".to_string(),
+            session_id: "sess0001".to_string(),
+            target_ref: "refs/heads/main".to_string(),
+            initiator_status: InitiatorStatus::Received,
+            status: ReviewerStatus::Finished,
+            parent_id: None,
+            started_at: "2026-01-11T00:00:00Z".to_string(),
+            updated_at: "2026-01-11T01:00:00Z".to_string(),
+            finished_at: Some("2026-01-11T01:00:00Z".to_string()),
+            current_phase: None,
+            eta: None,
+            blocking: Some(false),
+            verdict: Some(ReviewVerdict::Approve),
+            counts: SeverityCounts::zero(),
+            report_file: None,
+            notes: Vec::new(),
+            status_history: Vec::new(),
+        };
+        let mut pending = observer.clone();
+        pending.reviewer_id = "deadbeef".to_string();
+        pending.status = ReviewerStatus::InProgress;
+        pending.finished_at = None;
+        pending.blocking = None;
+        pending.verdict = None;
+        let write = |reviews: Vec<ReviewEntry>| -> anyhow::Result<()> {
+            let session = SessionFile {
+                schema_version: "1.0.0".to_string(),
+                session_date: "2026-01-11".to_string(),
+                repo_root: dir.path().to_string_lossy().to_string(),
+                reviewers: vec!["cafebabe".to_string(), "deadbeef".to_string()],
+                reviews,
+            };
+            let tmp = session_dir.join("_session.json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(&session)? + "\n")?;
+            fs::rename(&tmp, session_dir.join("_session.json"))?;
+            Ok(())
+        };
+        write(vec![observer.clone(), pending.clone()])?;
+
+        let waiter = {
+            let session_dir = session_dir.clone();
+            std::thread::spawn(move || {
+                let backoff = PollBackoff::from_secs(0.01, 0.05)?;
+                wait_for_reviews(&session_dir, None, None, true, backoff)
+            })
+        };
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        pending.status = ReviewerStatus::Finished;
+        pending.finished_at = Some("2026-01-11T03:00:00Z".to_string());
+        write(vec![observer, pending])?;
+
+        let first = waiter
+            .join()
+            .map_err(|_| anyhow::anyhow!("wait thread panicked"))??;
+        ensure!(first.is_some_and(|r| r.reviewer_id == "deadbeef"));
+        Ok(())
+    }
+
+    #[test]
+    fn poll_backoff_rejects_initial_above_max() -> anyhow::Result<()> {
+        let backoff = PollBackoff::from_secs(0.5, 2.0)?;
+        ensure!(backoff.initial == std::time::Duration::from_millis(500));
+        ensure!(backoff.max == std::time::Duration::from_secs(2));
+        ensure!(PollBackoff::from_secs(5.0, 1.0).is_err());
+        ensure!(PollBackoff::from_secs(0.0, 1.0).is_err());
+        ensure!(PollBackoff::from_secs(f64::NAN, 1.0).is_err());
         Ok(())
     }
 
@@ -1386,9 +4015,17 @@ mod tests {
             session_dir: Some(override_dir.clone()),
             repo_root: Some(repo_root.clone()),
             date: Some("2026-01-11".to_string()),
+            tz: None,
         };
-        let fallback = Date::from_calendar_date(2026, Month::January, 12)?;
-        let resolved = resolve_session_input(false, &args, fallback)?;
+        let fallback = Date::from_calendar_date(2026, Month::January, 12)?
+            .midnight()
+            .assume_utc();
+        let resolved = resolve_session_input(
+            EnvSource::default(),
+            &ConfigDefaults::default(),
+            &args,
+            fallback,
+        )?;
         ensure!(resolved.session_dir == override_dir);
         ensure!(resolved.repo_root == repo_root);
         ensure!(resolved.session_date.to_string() == "2026-01-11");
@@ -1402,12 +4039,16 @@ mod tests {
             session_dir: None,
             repo_root: Some(repo_root.path().to_path_buf()),
             date: Some("2026-01-11".to_string()),
+            tz: None,
         };
         let resolved = resolve_session_input_from_cwd(
-            false,
+            EnvSource::default(),
             &args,
-            Date::from_calendar_date(2026, Month::January, 12)?,
+            Date::from_calendar_date(2026, Month::January, 12)?
+                .midnight()
+                .assume_utc(),
             repo_root.path(),
+            &ConfigDefaults::default(),
         )?;
         let expected = paths::session_paths(
             repo_root.path(),
@@ -1429,12 +4070,16 @@ mod tests {
             session_dir: None,
             repo_root: None,
             date: Some("2026-01-11".to_string()),
+            tz: None,
         };
         let resolved = resolve_session_input_from_cwd(
-            false,
+            EnvSource::default(),
             &args,
-            Date::from_calendar_date(2026, Month::January, 12)?,
+            Date::from_calendar_date(2026, Month::January, 12)?
+                .midnight()
+                .assume_utc(),
             &cwd,
+            &ConfigDefaults::default(),
         )?;
         ensure!(resolved.repo_root == repo_root);
         ensure!(resolved.session_date.to_string() == "2026-01-11");
@@ -1443,4 +4088,78 @@ mod tests {
         ensure!(resolved.session_dir == expected.session_dir);
         Ok(())
     }
+
+    #[test]
+    fn parse_duration_secs_valid_and_invalid() -> anyhow::Result<()> {
+        ensure!(parse_duration_secs("45s")? == 45);
+        ensure!(parse_duration_secs("30m")? == 30 * 60);
+        ensure!(parse_duration_secs("1h")? == 60 * 60);
+        ensure!(parse_duration_secs("2d")? == 2 * 24 * 60 * 60);
+        ensure!(parse_duration_secs("0m")? == 0);
+        ensure!(parse_duration_secs("").is_err());
+        ensure!(parse_duration_secs("h").is_err());
+        ensure!(parse_duration_secs("10").is_err());
+        ensure!(parse_duration_secs("1w").is_err());
+        ensure!(parse_duration_secs("-1h").is_err());
+        ensure!(parse_duration_secs("1.5h").is_err());
+        ensure!(parse_duration_secs("99999999999999999999d").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_utc_offset_valid_and_invalid() -> anyhow::Result<()> {
+        ensure!(parse_utc_offset("UTC")? == UtcOffset::UTC);
+        ensure!(parse_utc_offset("-07:00")?.whole_hours() == -7);
+        ensure!(parse_utc_offset("+05:30")?.whole_minutes() == 330);
+        ensure!(parse_utc_offset("-07")?.whole_hours() == -7);
+        ensure!(parse_utc_offset("07:00").is_err());
+        ensure!(parse_utc_offset("+24:00").is_err());
+        ensure!(parse_utc_offset("+xx").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_session_input_applies_tz_to_default_date() -> anyhow::Result<()> {
+        let repo_root = tempfile::tempdir()?;
+        let now = OffsetDateTime::parse(
+            "2026-01-12T01:30:00Z",
+            &time::format_description::well_known::Rfc3339,
+        )?;
+        let mut args = SessionDirArgs {
+            session_dir: None,
+            repo_root: Some(repo_root.path().to_path_buf()),
+            date: None,
+            tz: Some("-07:00".to_string()),
+        };
+        let resolved = resolve_session_input_from_cwd(
+            EnvSource::default(),
+            &args,
+            now,
+            repo_root.path(),
+            &ConfigDefaults::default(),
+        )?;
+        ensure!(resolved.session_date.to_string() == "2026-01-11");
+
+        args.tz = None;
+        let resolved = resolve_session_input_from_cwd(
+            EnvSource::default(),
+            &args,
+            now,
+            repo_root.path(),
+            &ConfigDefaults::default(),
+        )?;
+        ensure!(resolved.session_date.to_string() == "2026-01-12");
+
+        args.tz = Some("-07:00".to_string());
+        args.date = Some("2026-01-12".to_string());
+        let resolved = resolve_session_input_from_cwd(
+            EnvSource::default(),
+            &args,
+            now,
+            repo_root.path(),
+            &ConfigDefaults::default(),
+        )?;
+        ensure!(resolved.session_date.to_string() == "2026-01-12");
+        Ok(())
+    }
 }
@@ -7,6 +7,8 @@ use std::path::{Path, PathBuf};
 use time::Date;
 
 const MAX_REF_LEN: usize = 64;
+/// Hex digits of the hash suffix appended to truncated refs (the top 32 bits of the hash).
+const REF_HASH_LEN: usize = 8;
 
 #[derive(Debug, Clone)]
 /// Resolved paths for a single session date under a given repo root.
@@ -36,7 +38,10 @@ pub fn session_paths(repo_root: &Path, session_date: Date) -> SessionPaths {
 /// Sanitize a target ref for use in filenames.
 ///
 /// Keeps ASCII alphanumerics and `.` / `-` / `_`; everything else becomes `_`.
-/// Leading/trailing underscores are trimmed and the final string is capped to 64 bytes.
+/// Leading/trailing underscores are trimmed and the final string is capped to 64 bytes so
+/// report filenames stay well under OS name limits. A ref that has to be cut keeps its first
+/// 55 bytes plus `-` and an 8-hex-digit hash of the full input, so distinct long refs with a
+/// shared prefix still map to distinct names.
 #[must_use]
 pub fn sanitize_ref(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
@@ -54,11 +59,22 @@ pub fn sanitize_ref(input: &str) -> String {
         trimmed.to_string()
     };
     if normalized.len() > MAX_REF_LEN {
-        normalized.truncate(MAX_REF_LEN);
+        let hash = content_hash(input) >> 32;
+        normalized.truncate(MAX_REF_LEN - REF_HASH_LEN - 1);
+        normalized = format!("{normalized}-{hash:08x}");
     }
     normalized
 }
 
+/// FNV-1a (64-bit) hash of `contents`, without extra deps.
+fn content_hash(contents: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    contents.bytes().fold(OFFSET_BASIS, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(PRIME)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +101,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn sanitize_ref_long_refs_stay_short_and_distinct() -> anyhow::Result<()> {
+        let a = format!("refs/pull/{}", "a".repeat(290));
+        let b = format!("refs/pull/{}b", "a".repeat(289));
+        ensure!(a.len() == 300 && b.len() == 300);
+
+        let (sa, sb) = (sanitize_ref(&a), sanitize_ref(&b));
+        ensure!(sa.len() == MAX_REF_LEN && sb.len() == MAX_REF_LEN);
+        ensure!(sa != sb);
+        ensure!(sa.starts_with("refs_pull_aaa"));
+        ensure!(sanitize_ref(&a) == sa);
+
+        let file_name = format!("12-00-00-000_{sa}_deadbeef.md");
+        ensure!(file_name.len() < 255);
+        Ok(())
+    }
 }
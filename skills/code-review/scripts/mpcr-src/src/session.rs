@@ -9,13 +9,16 @@
 //! The CLI (`mpcr`) is the intended interface for mutating session state.
 
 use crate::id;
-use crate::lock::{self, LockConfig};
+use crate::lock::{self, LockConfig, LockState};
 use crate::paths;
+use crate::trace::{self, TracePhase};
+use crate::warnings::{Warning, WarningCode, Warnings};
 use anyhow::Context;
 use clap::builder::PossibleValue;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -38,6 +41,8 @@ pub enum ReviewerStatus {
     Error,
     /// Waiting on an external dependency or intervention.
     Blocked,
+    /// Work set aside by the reviewer (context switch); expected to resume.
+    Paused,
 }
 
 impl ReviewerStatus {
@@ -57,6 +62,7 @@ impl ValueEnum for ReviewerStatus {
             Self::Cancelled,
             Self::Error,
             Self::Blocked,
+            Self::Paused,
         ]
     }
 
@@ -70,6 +76,9 @@ impl ValueEnum for ReviewerStatus {
             Self::Cancelled => PossibleValue::new("CANCELLED").help("Stopped before completion"),
             Self::Error => PossibleValue::new("ERROR").help("Fatal error; see notes"),
             Self::Blocked => PossibleValue::new("BLOCKED").help("Waiting on external dependency"),
+            Self::Paused => {
+                PossibleValue::new("PAUSED").help("Set aside by the reviewer; will resume")
+            }
         };
         Some(pv)
     }
@@ -86,6 +95,7 @@ impl std::str::FromStr for ReviewerStatus {
             s if s.eq_ignore_ascii_case("CANCELLED") => Ok(Self::Cancelled),
             s if s.eq_ignore_ascii_case("ERROR") => Ok(Self::Error),
             s if s.eq_ignore_ascii_case("BLOCKED") => Ok(Self::Blocked),
+            s if s.eq_ignore_ascii_case("PAUSED") => Ok(Self::Paused),
             _ => Err(anyhow::anyhow!("invalid ReviewerStatus: {s}")),
         }
     }
@@ -111,6 +121,14 @@ pub enum InitiatorStatus {
     Cancelled,
 }
 
+impl InitiatorStatus {
+    /// Whether the applicator is done with the entry (`APPLIED` or `CANCELLED`).
+    #[must_use]
+    pub const fn is_terminal(self) -> bool {
+        matches!(self, Self::Applied | Self::Cancelled)
+    }
+}
+
 impl ValueEnum for InitiatorStatus {
     fn value_variants<'a>() -> &'a [Self] {
         &[
@@ -207,6 +225,21 @@ impl ValueEnum for ReviewPhase {
     }
 }
 
+impl ReviewPhase {
+    /// Position in the canonical workflow, from `Ingestion` (0) to `ReportWriting` (5).
+    #[must_use]
+    pub const fn order(self) -> u8 {
+        match self {
+            Self::Ingestion => 0,
+            Self::DomainCoverage => 1,
+            Self::TheoremGeneration => 2,
+            Self::AdversarialProofs => 3,
+            Self::Synthesis => 4,
+            Self::ReportWriting => 5,
+        }
+    }
+}
+
 impl std::str::FromStr for ReviewPhase {
     type Err = anyhow::Error;
 
@@ -357,6 +390,30 @@ impl ValueEnum for NoteType {
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+/// Canonical name and help text for one [`NoteType`] (see [`note_type_catalog`]).
+pub struct NoteTypeInfo {
+    /// Canonical `snake_case` name, as accepted by `--note-type` and written to JSON.
+    pub name: String,
+    /// One-line description of when to use this type.
+    pub help: String,
+}
+
+/// List every [`NoteType`] with its name and help text.
+///
+/// Derived from the [`ValueEnum`] impl so the listing cannot drift from the CLI.
+#[must_use]
+pub fn note_type_catalog() -> Vec<NoteTypeInfo> {
+    NoteType::value_variants()
+        .iter()
+        .filter_map(ValueEnum::to_possible_value)
+        .map(|pv| NoteTypeInfo {
+            name: pv.get_name().to_string(),
+            help: pv.get_help().map_or_else(String::new, ToString::to_string),
+        })
+        .collect()
+}
+
 impl std::str::FromStr for NoteType {
     type Err = anyhow::Error;
 
@@ -418,6 +475,50 @@ impl std::str::FromStr for NoteType {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// Finding severity level, ordered from least (`Nit`) to most severe (`Blocker`).
+pub enum Severity {
+    /// Style or polish issue.
+    Nit,
+    /// Minor issue; safe to defer.
+    Minor,
+    /// Significant issue that should be fixed before merge.
+    Major,
+    /// Must be fixed; blocks merge.
+    Blocker,
+}
+
+impl ValueEnum for Severity {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Blocker, Self::Major, Self::Minor, Self::Nit]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let pv = match self {
+            Self::Blocker => PossibleValue::new("blocker").help("Must fix; blocks merge"),
+            Self::Major => PossibleValue::new("major").help("Should fix before merge"),
+            Self::Minor => PossibleValue::new("minor").help("Minor issue"),
+            Self::Nit => PossibleValue::new("nit").help("Style / polish"),
+        };
+        Some(pv)
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            s if s.eq_ignore_ascii_case("blocker") => Ok(Self::Blocker),
+            s if s.eq_ignore_ascii_case("major") => Ok(Self::Major),
+            s if s.eq_ignore_ascii_case("minor") => Ok(Self::Minor),
+            s if s.eq_ignore_ascii_case("nit") => Ok(Self::Nit),
+            _ => Err(anyhow::anyhow!("invalid Severity: {s}")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 /// Severity tallies for a review report.
@@ -443,9 +544,39 @@ impl SeverityCounts {
             nit: 0,
         }
     }
+
+    /// Tally for a single severity level.
+    #[must_use]
+    pub const fn count(&self, severity: Severity) -> u64 {
+        match severity {
+            Severity::Blocker => self.blocker,
+            Severity::Major => self.major,
+            Severity::Minor => self.minor,
+            Severity::Nit => self.nit,
+        }
+    }
+
+    /// Highest severity with a nonzero tally, if any.
+    #[must_use]
+    pub fn max_severity(&self) -> Option<Severity> {
+        [
+            Severity::Blocker,
+            Severity::Major,
+            Severity::Minor,
+            Severity::Nit,
+        ]
+        .into_iter()
+        .find(|severity| self.count(*severity) > 0)
+    }
+
+    /// Whether any finding at or above `min` was recorded.
+    #[must_use]
+    pub fn has_at_least(&self, min: Severity) -> bool {
+        self.max_severity().is_some_and(|max| max >= min)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 /// A structured note appended to a review entry's `notes` array.
 pub struct SessionNote {
@@ -458,6 +589,34 @@ pub struct SessionNote {
     pub note_type: NoteType,
     /// Arbitrary JSON content (string by default; object/array allowed).
     pub content: Value,
+    /// Free-form triage labels (short slugs, see [`validate_note_tag`]); absent on older notes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Severity of the observation itself, independent of the final report counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+}
+
+/// Maximum length of a note tag.
+pub const MAX_NOTE_TAG_LEN: usize = 32;
+
+/// Check that `tag` is a short slug: 1 to [`MAX_NOTE_TAG_LEN`] lowercase ASCII letters, digits,
+/// `-`, or `_`.
+///
+/// # Errors
+/// Returns an error describing why the tag is invalid.
+pub fn validate_note_tag(tag: &str) -> anyhow::Result<()> {
+    if tag.is_empty()
+        || tag.len() > MAX_NOTE_TAG_LEN
+        || !tag
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_'))
+    {
+        return Err(anyhow::anyhow!(
+            "invalid note tag {tag:?} (expected 1-{MAX_NOTE_TAG_LEN} chars of a-z, 0-9, '-', '_')"
+        ));
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -484,6 +643,13 @@ pub struct ReviewEntry {
     pub finished_at: Option<String>,
     /// Optional reviewer phase marker.
     pub current_phase: Option<ReviewPhase>,
+    /// Reviewer-reported RFC3339 estimate (UTC) of when the review will finish.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eta: Option<String>,
+    /// `Some(false)` marks an observe-only entry that `applicator wait` does not wait on; absent
+    /// (the default) means blocking. See [`ReviewEntry::is_blocking`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking: Option<bool>,
     /// Optional verdict (set when finished).
     pub verdict: Option<ReviewVerdict>,
     /// Severity counts extracted from the report.
@@ -492,8 +658,26 @@ pub struct ReviewEntry {
     pub report_file: Option<String>,
     /// Bidirectional notes between reviewer and applicator.
     pub notes: Vec<SessionNote>,
+    /// Recorded reviewer-status transitions, oldest first (absent when none were recorded).
+    /// mpcr only reads and preserves this field; its own mutations never add to it, so sessions
+    /// it writes stay readable by builds that predate the field.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub status_history: Vec<StatusTransition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// One entry in [`ReviewEntry::status_history`].
+pub struct StatusTransition {
+    /// Status the entry moved into.
+    pub status: ReviewerStatus,
+    /// RFC3339 timestamp (UTC) of the transition.
+    pub at: String,
 }
 
+/// `schema_version` written to newly created `_session.json` files.
+pub const CURRENT_SCHEMA_VERSION: &str = "1.0.0";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 /// Top-level session file stored as `_session.json` within a session directory.
@@ -514,7 +698,7 @@ pub struct SessionFile {
 #[serde(rename_all = "snake_case")]
 /// Report view selector for filtering review entries.
 pub enum ReportsView {
-    /// Reviews not in a terminal status (`INITIALIZING`, `IN_PROGRESS`, `BLOCKED`).
+    /// Reviews not in a terminal status (`INITIALIZING`, `IN_PROGRESS`, `BLOCKED`, `PAUSED`).
     Open,
     /// Reviews in a terminal status (`FINISHED`, `CANCELLED`, `ERROR`).
     Closed,
@@ -532,9 +716,83 @@ impl ReportsView {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// Entry field usable as a component of a composite review key (see
+/// [`ReportsFilters::review_key`]).
+pub enum ReviewKeyField {
+    /// `reviewer_id`.
+    ReviewerId,
+    /// `session_id`.
+    SessionId,
+    /// `target_ref`.
+    TargetRef,
+    /// Reviewer-owned `status`.
+    Status,
+    /// Applicator-owned `initiator_status`.
+    InitiatorStatus,
+    /// `verdict` (unset sorts first).
+    Verdict,
+    /// `current_phase` (unset sorts first).
+    CurrentPhase,
+    /// `parent_id` (unset sorts first).
+    ParentId,
+}
+
+impl ValueEnum for ReviewKeyField {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::ReviewerId,
+            Self::SessionId,
+            Self::TargetRef,
+            Self::Status,
+            Self::InitiatorStatus,
+            Self::Verdict,
+            Self::CurrentPhase,
+            Self::ParentId,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let pv = match self {
+            Self::ReviewerId => PossibleValue::new("reviewer_id"),
+            Self::SessionId => PossibleValue::new("session_id"),
+            Self::TargetRef => PossibleValue::new("target_ref"),
+            Self::Status => PossibleValue::new("status"),
+            Self::InitiatorStatus => PossibleValue::new("initiator_status"),
+            Self::Verdict => PossibleValue::new("verdict"),
+            Self::CurrentPhase => PossibleValue::new("current_phase"),
+            Self::ParentId => PossibleValue::new("parent_id"),
+        };
+        Some(pv)
+    }
+}
+
+impl ReviewKeyField {
+    fn value(self, entry: &ReviewEntry) -> String {
+        match self {
+            Self::ReviewerId => entry.reviewer_id.clone(),
+            Self::SessionId => entry.session_id.clone(),
+            Self::TargetRef => entry.target_ref.clone(),
+            Self::Status => wire_name(&entry.status),
+            Self::InitiatorStatus => wire_name(&entry.initiator_status),
+            Self::Verdict => entry.verdict.as_ref().map_or_else(String::new, wire_name),
+            Self::CurrentPhase => entry
+                .current_phase
+                .as_ref()
+                .map_or_else(String::new, wire_name),
+            Self::ParentId => entry
+                .parent_id
+                .as_deref()
+                .map_or_else(String::new, str::to_string),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 /// Optional filters applied on top of a [`ReportsView`].
+#[allow(clippy::struct_excessive_bools)]
 pub struct ReportsFilters {
     /// Only include reviews for this target ref.
     pub target_ref: Option<String>,
@@ -542,6 +800,10 @@ pub struct ReportsFilters {
     pub session_id: Option<String>,
     /// Only include reviews for this reviewer id.
     pub reviewer_id: Option<String>,
+    /// Only include reviews whose reviewer id is in this list (empty means no restriction).
+    pub reviewer_ids: Vec<String>,
+    /// Drop reviews whose reviewer id is in this list (applied after `reviewer_id`).
+    pub exclude_reviewer_ids: Vec<String>,
     /// Only include reviews with these reviewer-owned statuses.
     pub reviewer_statuses: Vec<ReviewerStatus>,
     /// Only include reviews with these initiator-owned statuses.
@@ -550,69 +812,329 @@ pub struct ReportsFilters {
     pub verdicts: Vec<ReviewVerdict>,
     /// Only include reviews with these phase markers.
     pub phases: Vec<ReviewPhase>,
+    /// Only include reviews whose phase is at or beyond this one (see [`ReviewPhase::order`]);
+    /// entries without a phase never match.
+    pub phase_at_least: Option<ReviewPhase>,
     /// Only include reviews that already have a report file.
     pub only_with_report: bool,
     /// Only include reviews that contain at least one note.
     pub only_with_notes: bool,
+    /// Only include reviews with a nonzero tally at or above this severity.
+    pub min_severity: Option<Severity>,
+    /// Only include non-terminal reviews whose `updated_at` is more than this many seconds old.
+    pub open_older_than_secs: Option<u64>,
+    /// Only include non-terminal reviews whose `started_at` is more than this many seconds old;
+    /// matching summaries carry [`ReviewSummary::breach_secs`].
+    pub sla_breach_secs: Option<u64>,
+    /// Only include reviews whose target ref matches this glob (`*` and `?`; see [`glob_match`]).
+    pub target_ref_glob: Option<String>,
+    /// Only include reviews with `finished_at` at or after this instant (unfinished entries never
+    /// match).
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub finished_after: Option<OffsetDateTime>,
+    /// Only include reviews with `finished_at` strictly before this instant (unfinished entries
+    /// never match).
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub finished_before: Option<OffsetDateTime>,
+    /// Only include reviews whose report file contents contain this substring. Entries without
+    /// a report are excluded; unreadable reports are excluded with a warning.
+    pub report_grep: Option<String>,
+    /// Match [`ReportsFilters::report_grep`] case-insensitively.
+    pub report_grep_ignore_case: bool,
+    /// Only include reviews with at least one note carrying any of these tags.
+    pub note_tags: Vec<String>,
+    /// Only include reviews with at least one note at or above this severity.
+    pub note_min_severity: Option<Severity>,
+    /// Only include reviews missing from this baseline or whose `updated_at` is newer than the
+    /// baseline's (see [`updated_at_baseline`]). Not serialized.
+    #[serde(skip)]
+    pub changed_since: Option<BTreeMap<(String, String), String>>,
+    /// Only include reviews whose newest note timestamp is within this many seconds of now;
+    /// reviews without notes never match.
+    pub note_since_secs: Option<u64>,
+    /// Composite key (components in priority order) the matching reviews are sorted by; empty
+    /// keeps session order.
+    pub review_key: Vec<ReviewKeyField>,
+    /// With `review_key`, keep only the most recently updated review for each key value.
+    pub unique: bool,
+    /// Only include terminal reviews the applicator has not finished with (`initiator_status`
+    /// not `APPLIED`/`CANCELLED`; see [`InitiatorStatus::is_terminal`]).
+    pub only_actionable: bool,
+}
+
+/// Index `session`'s reviews by `(reviewer_id, session_id)` to their `updated_at`, for
+/// [`ReportsFilters::changed_since`].
+#[must_use]
+pub fn updated_at_baseline(session: &SessionFile) -> BTreeMap<(String, String), String> {
+    session
+        .reviews
+        .iter()
+        .map(|entry| {
+            (
+                (entry.reviewer_id.clone(), entry.session_id.clone()),
+                entry.updated_at.clone(),
+            )
+        })
+        .collect()
+}
+
+/// Match `input` against a simple glob `pattern`.
+///
+/// `*` matches any run of characters (including none) and `?` matches exactly one character.
+/// Refs are not treated as paths: `/` is an ordinary character, so `feat/*` also matches
+/// `feat/a/b`. There is no escaping or character-class syntax.
+#[must_use]
+pub fn glob_match(pattern: &str, input: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let input: Vec<char> = input.chars().collect();
+    let (mut p, mut i) = (0_usize, 0_usize);
+    // Position of the last `*` in the pattern and the input index it is currently absorbing up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+    while i < input.len() {
+        match (pattern.get(p), input.get(i)) {
+            (Some('*'), _) => {
+                backtrack = Some((p, i));
+                p += 1;
+            }
+            (Some('?'), _) => {
+                p += 1;
+                i += 1;
+            }
+            (Some(pc), Some(ic)) if pc == ic => {
+                p += 1;
+                i += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_i)) => {
+                    backtrack = Some((star_p, star_i + 1));
+                    p = star_p + 1;
+                    i = star_i + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern
+        .get(p..)
+        .is_some_and(|rest| rest.iter().all(|c| *c == '*'))
 }
 
 impl ReportsFilters {
-    fn matches(&self, entry: &ReviewEntry) -> bool {
+    #[allow(clippy::too_many_lines)]
+    fn matches(&self, entry: &ReviewEntry, now: OffsetDateTime) -> anyhow::Result<bool> {
         if let Some(ref target_ref) = self.target_ref {
             if entry.target_ref != target_ref.as_str() {
-                return false;
+                return Ok(false);
+            }
+        }
+        if let Some(ref glob) = self.target_ref_glob {
+            if !glob_match(glob, &entry.target_ref) {
+                return Ok(false);
             }
         }
         if let Some(ref session_id) = self.session_id {
             if entry.session_id != session_id.as_str() {
-                return false;
+                return Ok(false);
             }
         }
         if let Some(ref reviewer_id) = self.reviewer_id {
             if entry.reviewer_id != reviewer_id.as_str() {
-                return false;
+                return Ok(false);
             }
         }
+        if !self.reviewer_ids.is_empty() && !self.reviewer_ids.contains(&entry.reviewer_id) {
+            return Ok(false);
+        }
+        if self.exclude_reviewer_ids.contains(&entry.reviewer_id) {
+            return Ok(false);
+        }
         if !self.reviewer_statuses.is_empty() && !self.reviewer_statuses.contains(&entry.status) {
-            return false;
+            return Ok(false);
         }
         if !self.initiator_statuses.is_empty()
             && !self.initiator_statuses.contains(&entry.initiator_status)
         {
-            return false;
+            return Ok(false);
         }
         if !self.verdicts.is_empty() {
             match entry.verdict {
                 Some(verdict) if self.verdicts.contains(&verdict) => {}
-                _ => return false,
+                _ => return Ok(false),
             }
         }
         if !self.phases.is_empty() {
             match entry.current_phase {
                 Some(phase) if self.phases.contains(&phase) => {}
-                _ => return false,
+                _ => return Ok(false),
+            }
+        }
+        if let Some(min_phase) = self.phase_at_least {
+            if entry
+                .current_phase
+                .is_none_or(|phase| phase.order() < min_phase.order())
+            {
+                return Ok(false);
             }
         }
         if self.only_with_report && entry.report_file.is_none() {
-            return false;
+            return Ok(false);
         }
         if self.only_with_notes && entry.notes.is_empty() {
-            return false;
+            return Ok(false);
+        }
+        if self.only_actionable && !entry.needs_applicator_action() {
+            return Ok(false);
+        }
+        if !self.note_tags.is_empty()
+            && !entry
+                .notes
+                .iter()
+                .any(|note| note.tags.iter().any(|tag| self.note_tags.contains(tag)))
+        {
+            return Ok(false);
+        }
+        if let Some(min_severity) = self.note_min_severity {
+            if !entry
+                .notes
+                .iter()
+                .any(|note| note.severity.is_some_and(|s| s >= min_severity))
+            {
+                return Ok(false);
+            }
+        }
+        if let Some(secs) = self.note_since_secs {
+            let mut newest: Option<OffsetDateTime> = None;
+            for note in &entry.notes {
+                let at = parse_ts(&note.timestamp).with_context(|| {
+                    format!(
+                        "parse note timestamp for reviewer_id={} session_id={}",
+                        entry.reviewer_id, entry.session_id
+                    )
+                })?;
+                newest = Some(newest.map_or(at, |prev| prev.max(at)));
+            }
+            let Some(newest) = newest else {
+                return Ok(false);
+            };
+            if now - newest > time::Duration::seconds(i64::try_from(secs)?) {
+                return Ok(false);
+            }
+        }
+        if let Some(ref baseline) = self.changed_since {
+            let key = (entry.reviewer_id.clone(), entry.session_id.clone());
+            if let Some(previous) = baseline.get(&key) {
+                let context = || {
+                    format!(
+                        "parse updated_at for reviewer_id={} session_id={}",
+                        entry.reviewer_id, entry.session_id
+                    )
+                };
+                let previous =
+                    parse_ts(previous).with_context(|| format!("{} (baseline)", context()))?;
+                let current = parse_ts(&entry.updated_at).with_context(context)?;
+                if current <= previous {
+                    return Ok(false);
+                }
+            }
+        }
+        if let Some(min_severity) = self.min_severity {
+            if !entry.counts.has_at_least(min_severity) {
+                return Ok(false);
+            }
+        }
+        if let Some(secs) = self.open_older_than_secs {
+            if entry.status.is_terminal() {
+                return Ok(false);
+            }
+            let updated_at = parse_ts(&entry.updated_at).with_context(|| {
+                format!(
+                    "parse updated_at for reviewer_id={} session_id={}",
+                    entry.reviewer_id, entry.session_id
+                )
+            })?;
+            if now - updated_at <= time::Duration::seconds(i64::try_from(secs)?) {
+                return Ok(false);
+            }
+        }
+        if let Some(secs) = self.sla_breach_secs {
+            if entry.status.is_terminal() || sla_breach_secs(entry, secs, now)? <= 0 {
+                return Ok(false);
+            }
         }
-        true
+        if self.finished_after.is_some() || self.finished_before.is_some() {
+            let Some(raw) = entry.finished_at.as_deref() else {
+                return Ok(false);
+            };
+            let finished_at = parse_ts(raw).with_context(|| {
+                format!(
+                    "parse finished_at for reviewer_id={} session_id={}",
+                    entry.reviewer_id, entry.session_id
+                )
+            })?;
+            if self.finished_after.is_some_and(|after| finished_at < after)
+                || self
+                    .finished_before
+                    .is_some_and(|before| finished_at >= before)
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
     }
 }
 
+/// Seconds by which `entry`'s age since `started_at` exceeds `threshold_secs` (zero or negative
+/// when within the threshold), for [`ReportsFilters::sla_breach_secs`].
+fn sla_breach_secs(
+    entry: &ReviewEntry,
+    threshold_secs: u64,
+    now: OffsetDateTime,
+) -> anyhow::Result<i64> {
+    let started_at = parse_ts(&entry.started_at).with_context(|| {
+        format!(
+            "parse started_at for reviewer_id={} session_id={}",
+            entry.reviewer_id, entry.session_id
+        )
+    })?;
+    let age = (now - started_at).whole_seconds();
+    Ok(age.saturating_sub(i64::try_from(threshold_secs)?))
+}
+
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 /// Options that control the shape of report listings.
+#[allow(clippy::struct_excessive_bools)]
 pub struct ReportsOptions {
     /// Include full notes for each review entry.
     pub include_notes: bool,
     /// Include report markdown contents when available.
     pub include_report_contents: bool,
+    /// Include the report file size in bytes (metadata only; contents are not read).
+    pub include_report_size: bool,
+    /// Only count matching entries per phase (see [`ReportsResult::phase_counts`]); no
+    /// summaries are built.
+    pub count_by_phase: bool,
+    /// Collapse notes identical in role, type, content, and timestamp (output only; the
+    /// session file is not modified). `notes_count` reports the collapsed count.
+    pub dedupe_notes: bool,
+    /// Only tally matching entries per verdict (see [`ReportsResult::verdict_summary`]); no
+    /// summaries are built. Requires [`ReportsView::Closed`].
+    pub verdict_summary: bool,
+    /// Attach [`ReviewAge`] (seconds since `updated_at` / `started_at`) to each summary.
+    pub include_age: bool,
+    /// Attach [`ReviewEntry::status_history`] to each summary (`[]` when none was recorded).
+    pub include_history: bool,
+    /// Replace each included note's `content` with [`REDACTED_NOTE_CONTENT`], keeping its
+    /// metadata and `notes_count` (applied after `dedupe_notes`).
+    pub redact_notes: bool,
+    /// Attach per-role note counts (`reviewer_notes_count` / `applicator_notes_count`) to
+    /// each summary (applied after `dedupe_notes`).
+    pub note_author_counts: bool,
 }
 
+/// Placeholder written over note contents under [`ReportsOptions::redact_notes`].
+pub const REDACTED_NOTE_CONTENT: &str = "[redacted]";
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(deny_unknown_fields)]
 /// Summary view of a review entry for reports.
@@ -637,6 +1159,9 @@ pub struct ReviewSummary {
     pub finished_at: Option<String>,
     /// Optional review phase marker.
     pub current_phase: Option<ReviewPhase>,
+    /// Reviewer-reported estimated completion time (if set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eta: Option<String>,
     /// Optional final verdict.
     pub verdict: Option<ReviewVerdict>,
     /// Severity counts from the report.
@@ -649,14 +1174,81 @@ pub struct ReviewSummary {
     /// Report markdown contents (when requested and available).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub report_contents: Option<String>,
-    /// Report read error (when requested and the file could not be read).
+    /// Report file size in bytes (when requested and the file could be stat'd).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_bytes: Option<u64>,
+    /// Report read error (when requested and the file could not be read or stat'd).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub report_error: Option<String>,
     /// Number of notes attached to the review entry.
     pub notes_count: usize,
+    /// Number of reviewer-authored notes (when requested).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewer_notes_count: Option<usize>,
+    /// Number of applicator-authored notes (when requested).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applicator_notes_count: Option<usize>,
     /// Optional full notes (included when requested).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<SessionNote>>,
+    /// Entry ages relative to the listing's `now` (when requested).
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub age: Option<ReviewAge>,
+    /// Recorded status transitions, oldest first (when requested).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_history: Option<Vec<StatusTransition>>,
+    /// Seconds past the [`ReportsFilters::sla_breach_secs`] threshold (when that filter is set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breach_secs: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+/// Entry ages in whole seconds; `None` (null) when the timestamp cannot be parsed.
+pub struct ReviewAge {
+    /// Seconds since `updated_at`.
+    pub updated_age_secs: Option<i64>,
+    /// Seconds since `started_at`.
+    pub started_age_secs: Option<i64>,
+}
+
+/// Column names for tabular report output, in order (see [`ReviewSummary::table_row`]).
+pub const REPORT_TABLE_COLUMNS: [&str; 9] = [
+    "reviewer_id",
+    "session_id",
+    "target_ref",
+    "status",
+    "verdict",
+    "blocker",
+    "major",
+    "minor",
+    "nit",
+];
+
+/// Serialized (wire) name of a unit enum variant, e.g. `IN_PROGRESS`.
+#[must_use]
+pub fn wire_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+impl ReviewSummary {
+    /// Unescaped cell values matching [`REPORT_TABLE_COLUMNS`] (empty verdict when unset).
+    #[must_use]
+    pub fn table_row(&self) -> [String; 9] {
+        [
+            self.reviewer_id.clone(),
+            self.session_id.clone(),
+            self.target_ref.clone(),
+            wire_name(&self.status),
+            self.verdict.as_ref().map_or_else(String::new, wire_name),
+            self.counts.blocker.to_string(),
+            self.counts.major.to_string(),
+            self.counts.minor.to_string(),
+            self.counts.nit.to_string(),
+        ]
+    }
 }
 
 fn strip_repo_root_best_effort(repo_root: &Path, path: &Path) -> Option<PathBuf> {
@@ -684,6 +1276,17 @@ fn strip_repo_root_best_effort(repo_root: &Path, path: &Path) -> Option<PathBuf>
     None
 }
 
+/// Drop later copies of notes that match an earlier note exactly, keeping the original order.
+fn dedupe_session_notes(notes: &[SessionNote]) -> Vec<SessionNote> {
+    let mut out: Vec<SessionNote> = Vec::with_capacity(notes.len());
+    for note in notes {
+        if !out.contains(note) {
+            out.push(note.clone());
+        }
+    }
+    out
+}
+
 fn resolve_report_file_path(repo_root: &Path, session_dir: &Path, report_file: &str) -> PathBuf {
     let report_file_path = Path::new(report_file);
     if report_file_path.is_absolute() {
@@ -702,20 +1305,57 @@ fn resolve_report_file_path(repo_root: &Path, session_dir: &Path, report_file: &
 
 impl ReviewEntry {
     /// Produce a summarized view suitable for report listings.
+    ///
+    /// `now` is the reference time for [`ReportsOptions::include_age`].
     #[must_use]
     pub fn summary(
         &self,
         repo_root: &Path,
         session_dir: &Path,
         options: ReportsOptions,
+        now: OffsetDateTime,
+    ) -> ReviewSummary {
+        self.summary_with_warnings(repo_root, session_dir, options, now, &mut Warnings::new())
+    }
+
+    /// Like [`ReviewEntry::summary`], additionally recording report read/stat failures and
+    /// unparseable timestamps in `warnings` (report failures are still reported inline as
+    /// `report_error`).
+    #[must_use]
+    pub fn summary_with_warnings(
+        &self,
+        repo_root: &Path,
+        session_dir: &Path,
+        options: ReportsOptions,
+        now: OffsetDateTime,
+        warnings: &mut Warnings,
     ) -> ReviewSummary {
         let report_path = self.report_file.as_ref().map(|file| {
             resolve_report_file_path(repo_root, session_dir, file)
                 .to_string_lossy()
                 .to_string()
         });
+        let all_notes = if options.dedupe_notes {
+            dedupe_session_notes(&self.notes)
+        } else {
+            self.notes.clone()
+        };
+        let notes_count = all_notes.len();
+        let role_count = |role: NoteRole| {
+            options
+                .note_author_counts
+                .then(|| all_notes.iter().filter(|note| note.role == role).count())
+        };
+        let reviewer_notes_count = role_count(NoteRole::Reviewer);
+        let applicator_notes_count = role_count(NoteRole::Applicator);
         let notes = if options.include_notes {
-            Some(self.notes.clone())
+            let mut notes = all_notes;
+            if options.redact_notes {
+                for note in &mut notes {
+                    note.content = Value::String(REDACTED_NOTE_CONTENT.to_string());
+                }
+            }
+            Some(notes)
         } else {
             None
         };
@@ -729,7 +1369,33 @@ impl ReviewEntry {
                         report_contents = Some(contents);
                     }
                     Err(err) => {
-                        report_error = Some(format!("read report file {}: {err}", path.display()));
+                        let message = format!("read report file {}: {err}", path.display());
+                        warnings.push(self.report_warning(
+                            WarningCode::ReportRead,
+                            &message,
+                            &path,
+                        ));
+                        report_error = Some(message);
+                    }
+                }
+            }
+        }
+        let mut report_bytes = None;
+        if options.include_report_size {
+            if let Some(ref file) = self.report_file {
+                let path = resolve_report_file_path(repo_root, session_dir, file);
+                match fs::metadata(&path) {
+                    Ok(meta) => {
+                        report_bytes = Some(meta.len());
+                    }
+                    Err(err) => {
+                        let message = format!("stat report file {}: {err}", path.display());
+                        warnings.push(self.report_warning(
+                            WarningCode::ReportStat,
+                            &message,
+                            &path,
+                        ));
+                        report_error.get_or_insert(message);
                     }
                 }
             }
@@ -745,14 +1411,80 @@ impl ReviewEntry {
             updated_at: self.updated_at.clone(),
             finished_at: self.finished_at.clone(),
             current_phase: self.current_phase,
+            eta: self.eta.clone(),
             verdict: self.verdict,
             counts: self.counts.clone(),
             report_file: self.report_file.clone(),
             report_path,
             report_contents,
+            report_bytes,
             report_error,
-            notes_count: self.notes.len(),
+            notes_count,
+            reviewer_notes_count,
+            applicator_notes_count,
             notes,
+            age: options.include_age.then(|| ReviewAge {
+                updated_age_secs: self.age_secs("updated_at", &self.updated_at, now, warnings),
+                started_age_secs: self.age_secs("started_at", &self.started_at, now, warnings),
+            }),
+            status_history: options.include_history.then(|| self.status_history.clone()),
+            breach_secs: None,
+        }
+    }
+
+    fn age_secs(
+        &self,
+        field: &str,
+        raw: &str,
+        now: OffsetDateTime,
+        warnings: &mut Warnings,
+    ) -> Option<i64> {
+        match parse_ts(raw) {
+            Ok(ts) => Some((now - ts).whole_seconds()),
+            Err(err) => {
+                warnings.push(Warning {
+                    code: WarningCode::TimestampParse,
+                    message: format!("parse {field} {raw:?}: {err}"),
+                    reviewer_id: Some(self.reviewer_id.clone()),
+                    session_id: Some(self.session_id.clone()),
+                    path: None,
+                });
+                None
+            }
+        }
+    }
+
+    /// Whether this entry's report contains `needle` (see [`ReportsFilters::report_grep`]).
+    fn report_contains(
+        &self,
+        repo_root: &Path,
+        session_dir: &Path,
+        needle: &str,
+        ignore_case: bool,
+        warnings: &mut Warnings,
+    ) -> bool {
+        let Some(ref file) = self.report_file else {
+            return false;
+        };
+        let path = resolve_report_file_path(repo_root, session_dir, file);
+        match fs::read_to_string(&path) {
+            Ok(contents) if ignore_case => contents.to_lowercase().contains(&needle.to_lowercase()),
+            Ok(contents) => contents.contains(needle),
+            Err(err) => {
+                let message = format!("read report file {}: {err}", path.display());
+                warnings.push(self.report_warning(WarningCode::ReportRead, &message, &path));
+                false
+            }
+        }
+    }
+
+    fn report_warning(&self, code: WarningCode, message: &str, path: &Path) -> Warning {
+        Warning {
+            code,
+            message: message.to_string(),
+            reviewer_id: Some(self.reviewer_id.clone()),
+            session_id: Some(self.session_id.clone()),
+            path: Some(path.to_string_lossy().to_string()),
         }
     }
 }
@@ -775,41 +1507,489 @@ pub struct ReportsResult {
     pub total_reviews: usize,
     /// Number of reviews matching the view + filters.
     pub matching_reviews: usize,
-    /// Matching review summaries.
+    /// Matching review summaries (empty under [`ReportsOptions::count_by_phase`] and
+    /// [`ReportsOptions::verdict_summary`]).
     pub reviews: Vec<ReviewSummary>,
+    /// Matching entries per phase wire name, plus [`PHASE_NULL_BUCKET`] for entries without a
+    /// phase. Only set under [`ReportsOptions::count_by_phase`]; every bucket is present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase_counts: Option<BTreeMap<String, usize>>,
+    /// Verdict histogram over matching entries. Only set under
+    /// [`ReportsOptions::verdict_summary`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_summary: Option<VerdictSummary>,
+    /// Live lock state of the session directory (set by callers that request it).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_state: Option<LockState>,
 }
 
-/// Build a report listing for the given session data.
+#[derive(Debug, Clone, Serialize)]
+/// Verdict histogram for closed reviews.
+pub struct VerdictSummary {
+    /// Matching entries per verdict wire name, plus [`VERDICT_NONE_BUCKET`] for entries without
+    /// a verdict. Every bucket is present.
+    pub counts: BTreeMap<String, usize>,
+    /// Sum of all buckets (equals [`ReportsResult::matching_reviews`]).
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Top-level aggregates over a listing's matching reviews (see [`summarize_reports`]).
+pub struct ReportsAggregate {
+    /// View selector used for the listing.
+    pub view: ReportsView,
+    /// Total number of reviews in the session.
+    pub total_reviews: usize,
+    /// Number of reviews matching the view + filters.
+    pub matching_reviews: usize,
+    /// Matching entries per reviewer status wire name. Every status is present.
+    pub status_counts: BTreeMap<String, usize>,
+    /// Matching entries per verdict wire name, plus [`VERDICT_NONE_BUCKET`]. Every bucket is
+    /// present.
+    pub verdict_counts: BTreeMap<String, usize>,
+    /// Matching entries per phase wire name, plus [`PHASE_NULL_BUCKET`]. Every bucket is present.
+    pub phase_counts: BTreeMap<String, usize>,
+    /// Severity counts summed over matching entries.
+    pub severity_totals: SeverityCounts,
+}
+
+/// Aggregate `result.reviews` by status, verdict, and phase, and sum their severity counts.
+///
+/// The aggregates are computed from the summaries, so they are empty for listings built under
+/// [`ReportsOptions::count_by_phase`] or [`ReportsOptions::verdict_summary`].
 #[must_use]
+pub fn summarize_reports(result: &ReportsResult) -> ReportsAggregate {
+    let mut status_counts: BTreeMap<String, usize> = ReviewerStatus::value_variants()
+        .iter()
+        .map(|status| (wire_name(status), 0))
+        .collect();
+    let mut verdict_counts = empty_verdict_summary().counts;
+    let mut phase_counts = empty_phase_counts();
+    let mut severity_totals = SeverityCounts::zero();
+    for review in &result.reviews {
+        *status_counts.entry(wire_name(&review.status)).or_default() += 1;
+        let verdict = review.verdict.map_or_else(
+            || VERDICT_NONE_BUCKET.to_string(),
+            |verdict| wire_name(&verdict),
+        );
+        *verdict_counts.entry(verdict).or_default() += 1;
+        let phase = review
+            .current_phase
+            .map_or_else(|| PHASE_NULL_BUCKET.to_string(), |phase| wire_name(&phase));
+        *phase_counts.entry(phase).or_default() += 1;
+        severity_totals.blocker = severity_totals
+            .blocker
+            .saturating_add(review.counts.blocker);
+        severity_totals.major = severity_totals.major.saturating_add(review.counts.major);
+        severity_totals.minor = severity_totals.minor.saturating_add(review.counts.minor);
+        severity_totals.nit = severity_totals.nit.saturating_add(review.counts.nit);
+    }
+    ReportsAggregate {
+        view: result.view,
+        total_reviews: result.total_reviews,
+        matching_reviews: result.matching_reviews,
+        status_counts,
+        verdict_counts,
+        phase_counts,
+        severity_totals,
+    }
+}
+
+/// Key used in [`ReportsResult::phase_counts`] for entries with no `current_phase`.
+pub const PHASE_NULL_BUCKET: &str = "null";
+
+/// Key used in [`VerdictSummary::counts`] for entries with no `verdict`.
+pub const VERDICT_NONE_BUCKET: &str = "none";
+
+fn empty_phase_counts() -> BTreeMap<String, usize> {
+    ReviewPhase::value_variants()
+        .iter()
+        .map(wire_name)
+        .chain(std::iter::once(PHASE_NULL_BUCKET.to_string()))
+        .map(|key| (key, 0))
+        .collect()
+}
+
+fn empty_verdict_summary() -> VerdictSummary {
+    VerdictSummary {
+        counts: ReviewVerdict::value_variants()
+            .iter()
+            .map(wire_name)
+            .chain(std::iter::once(VERDICT_NONE_BUCKET.to_string()))
+            .map(|key| (key, 0))
+            .collect(),
+        total: 0,
+    }
+}
+
+/// Build a report listing for the given session data.
+///
+/// `now` is the reference time for age-based filters.
+///
+/// # Errors
+/// Returns an error if an age-based filter is set and a matching entry has an unparseable
+/// timestamp, or if [`ReportsOptions::verdict_summary`] is set for a view other than
+/// [`ReportsView::Closed`].
 pub fn collect_reports(
     session: &SessionFile,
     locator: &SessionLocator,
     view: ReportsView,
     filters: ReportsFilters,
     options: ReportsOptions,
-) -> ReportsResult {
+    now: OffsetDateTime,
+) -> anyhow::Result<ReportsResult> {
+    collect_reports_with_warnings(
+        session,
+        locator,
+        view,
+        filters,
+        options,
+        now,
+        &mut Warnings::new(),
+    )
+}
+
+/// Like [`collect_reports`], additionally recording non-fatal problems (e.g. unreadable report
+/// files) in `warnings`.
+///
+/// # Errors
+/// Same as [`collect_reports`].
+pub fn collect_reports_with_warnings(
+    session: &SessionFile,
+    locator: &SessionLocator,
+    view: ReportsView,
+    filters: ReportsFilters,
+    options: ReportsOptions,
+    now: OffsetDateTime,
+    warnings: &mut Warnings,
+) -> anyhow::Result<ReportsResult> {
+    if options.verdict_summary && view != ReportsView::Closed {
+        return Err(anyhow::anyhow!("verdict summary requires the closed view"));
+    }
     let total_reviews = session.reviews.len();
     let repo_root = Path::new(&session.repo_root);
     let mut reviews = Vec::new();
-    for entry in &session.reviews {
-        if !filters.matches(entry) {
-            continue;
+    let mut phase_counts = options.count_by_phase.then(empty_phase_counts);
+    let mut verdict_summary = options.verdict_summary.then(empty_verdict_summary);
+    let mut matching_reviews = 0;
+    for entry in select_review_entries(session, locator, Some(view), &filters, now, warnings)? {
+        matching_reviews += 1;
+        if let Some(summary) = verdict_summary.as_mut() {
+            let key = entry.verdict.map_or_else(
+                || VERDICT_NONE_BUCKET.to_string(),
+                |verdict| wire_name(&verdict),
+            );
+            *summary.counts.entry(key).or_default() += 1;
+            summary.total += 1;
+        }
+        if let Some(counts) = phase_counts.as_mut() {
+            let key = entry
+                .current_phase
+                .map_or_else(|| PHASE_NULL_BUCKET.to_string(), |phase| wire_name(&phase));
+            *counts.entry(key).or_default() += 1;
         }
-        if !view.matches_status(entry.status) {
+        if options.count_by_phase || options.verdict_summary {
             continue;
         }
-        reviews.push(entry.summary(repo_root, locator.session_dir(), options));
+        let mut summary =
+            entry.summary_with_warnings(repo_root, locator.session_dir(), options, now, warnings);
+        if let Some(secs) = filters.sla_breach_secs {
+            summary.breach_secs = Some(sla_breach_secs(entry, secs, now)?);
+        }
+        reviews.push(summary);
     }
 
-    ReportsResult {
+    Ok(ReportsResult {
         session_dir: locator.session_dir().to_string_lossy().to_string(),
         session_file: locator.session_file().to_string_lossy().to_string(),
         view,
         filters,
         options,
         total_reviews,
-        matching_reviews: reviews.len(),
+        matching_reviews,
         reviews,
+        phase_counts,
+        verdict_summary,
+        lock_state: None,
+    })
+}
+
+/// Select the full review entries matching `filters`, in session order (or by
+/// [`ReportsFilters::review_key`]); no view is applied.
+///
+/// Unlike [`collect_reports_with_warnings`], entries are returned as stored rather than
+/// projected into [`ReviewSummary`]s. Unreadable reports under
+/// [`ReportsFilters::report_grep`] are recorded in `warnings`.
+///
+/// # Errors
+/// Same as [`collect_reports`].
+pub fn filter_review_entries<'a>(
+    session: &'a SessionFile,
+    locator: &SessionLocator,
+    filters: &ReportsFilters,
+    now: OffsetDateTime,
+    warnings: &mut Warnings,
+) -> anyhow::Result<Vec<&'a ReviewEntry>> {
+    select_review_entries(session, locator, None, filters, now, warnings)
+}
+
+fn select_review_entries<'a>(
+    session: &'a SessionFile,
+    locator: &SessionLocator,
+    view: Option<ReportsView>,
+    filters: &ReportsFilters,
+    now: OffsetDateTime,
+    warnings: &mut Warnings,
+) -> anyhow::Result<Vec<&'a ReviewEntry>> {
+    if filters.unique && filters.review_key.is_empty() {
+        return Err(anyhow::anyhow!("unique requires a review key"));
+    }
+    let repo_root = Path::new(&session.repo_root);
+    let mut entries = Vec::new();
+    for entry in &session.reviews {
+        if !filters.matches(entry, now)? {
+            continue;
+        }
+        if view.is_some_and(|view| !view.matches_status(entry.status)) {
+            continue;
+        }
+        if let Some(ref needle) = filters.report_grep {
+            if !entry.report_contains(
+                repo_root,
+                locator.session_dir(),
+                needle,
+                filters.report_grep_ignore_case,
+                warnings,
+            ) {
+                continue;
+            }
+        }
+        entries.push(entry);
+    }
+    if filters.review_key.is_empty() {
+        return Ok(entries);
+    }
+
+    let key = |entry: &ReviewEntry| -> Vec<String> {
+        filters
+            .review_key
+            .iter()
+            .map(|field| field.value(entry))
+            .collect()
+    };
+    let mut keyed: Vec<(Vec<String>, Option<OffsetDateTime>, &ReviewEntry)> = entries
+        .into_iter()
+        .map(|entry| (key(entry), parse_ts(&entry.updated_at).ok(), entry))
+        .collect();
+    if filters.unique {
+        // Newest first within a key so `dedup_by` keeps the most recently updated entry.
+        keyed.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)));
+        keyed.dedup_by(|a, b| a.0 == b.0);
+    } else {
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+    Ok(keyed.into_iter().map(|(_, _, entry)| entry).collect())
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// A single note joined with the identity of the review entry that owns it.
+pub struct FlattenedNote {
+    /// Owning entry's reviewer id.
+    pub reviewer_id: String,
+    /// Owning entry's session id.
+    pub session_id: String,
+    /// Owning entry's target ref.
+    pub target_ref: String,
+    /// The note itself (`role`, `timestamp`, `type`, `content`).
+    #[serde(flatten)]
+    pub note: SessionNote,
+}
+
+/// Flatten the notes of every review in a listing into one record per note.
+///
+/// Reviews listed without notes (see [`ReportsOptions::include_notes`]) contribute nothing.
+#[must_use]
+pub fn flatten_report_notes(result: &ReportsResult) -> Vec<FlattenedNote> {
+    result
+        .reviews
+        .iter()
+        .flat_map(|review| {
+            review.notes.iter().flatten().map(|note| FlattenedNote {
+                reviewer_id: review.reviewer_id.clone(),
+                session_id: review.session_id.clone(),
+                target_ref: review.target_ref.clone(),
+                note: note.clone(),
+            })
+        })
+        .collect()
+}
+
+impl SessionNote {
+    /// Note content as plain text: string content unwrapped, anything else pretty-printed JSON.
+    #[must_use]
+    pub fn content_text(&self) -> String {
+        match &self.content {
+            Value::String(text) => text.clone(),
+            other => serde_json::to_string_pretty(other)
+                .map_or_else(|_| other.to_string(), std::convert::identity),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result returned by [`list_reviewer_reviews`].
+pub struct ReviewerListResult {
+    /// Session directory containing `_session.json`.
+    pub session_dir: String,
+    /// Full path to `_session.json`.
+    pub session_file: String,
+    /// Reviewer id the listing is for.
+    pub reviewer_id: String,
+    /// Open and closed reviews for the reviewer, oldest `updated_at` first.
+    pub reviews: Vec<ReviewSummary>,
+}
+
+/// List one reviewer's open and closed reviews, sorted by `updated_at`.
+///
+/// This is the union of the [`ReportsView::Open`] and [`ReportsView::Closed`] listings filtered by
+/// `reviewer_id`. A missing session (`None`) yields an empty listing.
+///
+/// # Errors
+/// Returns an error if `reviewer_id` is invalid or an entry has an unparseable `updated_at`.
+pub fn list_reviewer_reviews(
+    session: Option<&SessionFile>,
+    locator: &SessionLocator,
+    reviewer_id: &str,
+    now: OffsetDateTime,
+) -> anyhow::Result<ReviewerListResult> {
+    validate_id8(reviewer_id, "reviewer_id")?;
+    let mut reviews = Vec::new();
+    if let Some(session) = session {
+        let filters = ReportsFilters {
+            reviewer_id: Some(reviewer_id.to_string()),
+            ..ReportsFilters::default()
+        };
+        for view in [ReportsView::Open, ReportsView::Closed] {
+            let listing = collect_reports(
+                session,
+                locator,
+                view,
+                filters.clone(),
+                ReportsOptions::default(),
+                now,
+            )?;
+            for summary in listing.reviews {
+                reviews.push((parse_ts(&summary.updated_at)?, summary));
+            }
+        }
+    }
+    reviews.sort_by_key(|(updated_at, _)| *updated_at);
+
+    Ok(ReviewerListResult {
+        session_dir: locator.session_dir().to_string_lossy().to_string(),
+        session_file: locator.session_file().to_string_lossy().to_string(),
+        reviewer_id: reviewer_id.to_string(),
+        reviews: reviews.into_iter().map(|(_, summary)| summary).collect(),
+    })
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+/// Verdict counts across a session's review entries.
+pub struct VerdictBreakdown {
+    /// Reviews with verdict `APPROVE`.
+    pub approve: usize,
+    /// Reviews with verdict `REQUEST_CHANGES`.
+    pub request_changes: usize,
+    /// Reviews with verdict `BLOCK`.
+    pub block: usize,
+    /// Reviews without a verdict yet.
+    pub none: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// A finished review that still needs applicator follow-up.
+pub struct ActionableReview {
+    /// Reviewer id.
+    pub reviewer_id: String,
+    /// Session id.
+    pub session_id: String,
+    /// Target reference under review.
+    pub target_ref: String,
+    /// Reviewer-owned (terminal) status.
+    pub status: ReviewerStatus,
+    /// Current applicator-owned status.
+    pub initiator_status: InitiatorStatus,
+    /// Optional final verdict.
+    pub verdict: Option<ReviewVerdict>,
+    /// Report path relative to the repo root (if finalized).
+    pub report_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// One-shot applicator decision summary for a session.
+pub struct ApplicatorSummary {
+    /// Total number of reviews in the session.
+    pub total_reviews: usize,
+    /// Verdict breakdown across all reviews.
+    pub verdicts: VerdictBreakdown,
+    /// Sum of BLOCKER findings across all reviews.
+    pub total_blockers: u64,
+    /// Sum of MAJOR findings across all reviews.
+    pub total_majors: u64,
+    /// Reviews in a terminal reviewer status whose `initiator_status` is not yet
+    /// `APPLIED` or `CANCELLED`.
+    pub actionable: Vec<ActionableReview>,
+}
+
+impl ReviewEntry {
+    /// Whether `applicator wait` should wait on this entry while it is open (false only for
+    /// observe-only entries).
+    #[must_use]
+    pub fn is_blocking(&self) -> bool {
+        self.blocking != Some(false)
+    }
+
+    /// Whether the reviewer is done but the applicator has not closed out this entry yet.
+    #[must_use]
+    pub const fn needs_applicator_action(&self) -> bool {
+        self.status.is_terminal() && !self.initiator_status.is_terminal()
+    }
+}
+
+/// Summarize a session for the applicator (read-only).
+#[must_use]
+pub fn summarize_for_applicator(session: &SessionFile) -> ApplicatorSummary {
+    let mut verdicts = VerdictBreakdown::default();
+    let mut total_blockers: u64 = 0;
+    let mut total_majors: u64 = 0;
+    let mut actionable = Vec::new();
+    for entry in &session.reviews {
+        match entry.verdict {
+            Some(ReviewVerdict::Approve) => verdicts.approve += 1,
+            Some(ReviewVerdict::RequestChanges) => verdicts.request_changes += 1,
+            Some(ReviewVerdict::Block) => verdicts.block += 1,
+            None => verdicts.none += 1,
+        }
+        total_blockers = total_blockers.saturating_add(entry.counts.blocker);
+        total_majors = total_majors.saturating_add(entry.counts.major);
+        if entry.needs_applicator_action() {
+            actionable.push(ActionableReview {
+                reviewer_id: entry.reviewer_id.clone(),
+                session_id: entry.session_id.clone(),
+                target_ref: entry.target_ref.clone(),
+                status: entry.status,
+                initiator_status: entry.initiator_status,
+                verdict: entry.verdict,
+                report_file: entry.report_file.clone(),
+            });
+        }
+    }
+    ApplicatorSummary {
+        total_reviews: session.reviews.len(),
+        verdicts,
+        total_blockers,
+        total_majors,
+        actionable,
     }
 }
 
@@ -817,7 +1997,11 @@ fn format_ts(now: OffsetDateTime) -> anyhow::Result<String> {
     now.format(&Rfc3339).context("format RFC3339 timestamp")
 }
 
-fn parse_ts(s: &str) -> anyhow::Result<OffsetDateTime> {
+/// Parse an RFC 3339 timestamp as stored in `_session.json`.
+///
+/// # Errors
+/// Returns an error if `s` is not a valid RFC 3339 timestamp.
+pub fn parse_ts(s: &str) -> anyhow::Result<OffsetDateTime> {
     OffsetDateTime::parse(s, &Rfc3339).context("parse RFC3339 timestamp")
 }
 
@@ -826,8 +2010,17 @@ fn session_file_path(session_dir: &Path) -> PathBuf {
 }
 
 fn read_session_file(session_dir: &Path) -> anyhow::Result<SessionFile> {
-    let path = session_file_path(session_dir);
-    let raw = fs::read_to_string(&path)
+    trace::timed(TracePhase::Read, || {
+        load_session_snapshot(&session_file_path(session_dir))
+    })
+}
+
+/// Load and parse a session file from an arbitrary path (e.g. a saved copy of `_session.json`).
+///
+/// # Errors
+/// Returns an error if the file cannot be read or parsed.
+pub fn load_session_snapshot(path: &Path) -> anyhow::Result<SessionFile> {
+    let raw = fs::read_to_string(path)
         .with_context(|| format!("read session file {}", path.display()))?;
     let parsed: SessionFile =
         serde_json::from_str(&raw).with_context(|| format!("parse JSON {}", path.display()))?;
@@ -843,15 +2036,78 @@ pub fn load_session(session: &SessionLocator) -> anyhow::Result<SessionFile> {
 }
 
 fn write_session_file_atomic(
+    locator: &SessionLocator,
+    owner: &str,
+    session: &SessionFile,
+) -> anyhow::Result<()> {
+    trace::timed(TracePhase::Write, || {
+        replace_session_file(locator, owner, session)
+    })
+}
+
+/// Canonical `_session.json` bytes: pretty-printed in struct field order, newline-terminated.
+fn session_file_body(session: &SessionFile) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(session).context("serialize session JSON")? + "\n")
+}
+
+#[cfg(unix)]
+fn same_device(a: &Path, b: &Path) -> anyhow::Result<bool> {
+    use std::os::unix::fs::MetadataExt;
+    let dev = |path: &Path| {
+        fs::metadata(path)
+            .map(|meta| meta.dev())
+            .with_context(|| format!("stat {}", path.display()))
+    };
+    Ok(dev(a)? == dev(b)?)
+}
+
+#[cfg(not(unix))]
+fn same_device(_a: &Path, _b: &Path) -> anyhow::Result<bool> {
+    Err(anyhow::anyhow!(
+        "device identity cannot be checked on this platform"
+    ))
+}
+
+/// Decide whether `requested` may stage atomic writes for `session_dir`: kept when
+/// `same_device` confirms they share a filesystem, else `None` plus a warning explaining why.
+fn choose_tmp_dir(
     session_dir: &Path,
+    requested: &Path,
+    same_device: impl Fn(&Path, &Path) -> anyhow::Result<bool>,
+) -> (Option<PathBuf>, Option<Warning>) {
+    let reason = match same_device(requested, session_dir) {
+        Ok(true) => return (Some(requested.to_path_buf()), None),
+        Ok(false) => "it is on a different device".to_string(),
+        Err(err) => format!("{err:#}"),
+    };
+    let warning = Warning {
+        code: WarningCode::TmpDirFallback,
+        message: format!(
+            "not staging temp file in {}: {reason}; using the session dir",
+            requested.display()
+        ),
+        reviewer_id: None,
+        session_id: None,
+        path: Some(requested.to_string_lossy().to_string()),
+    };
+    (None, Some(warning))
+}
+
+fn replace_session_file(
+    locator: &SessionLocator,
     owner: &str,
     session: &SessionFile,
 ) -> anyhow::Result<()> {
+    let session_dir = locator.session_dir();
     fs::create_dir_all(session_dir)
         .with_context(|| format!("create session dir {}", session_dir.display()))?;
     let session_file = session_file_path(session_dir);
-    let tmp = session_dir.join(format!("_session.json.tmp.{owner}"));
-    let body = serde_json::to_string_pretty(session).context("serialize session JSON")? + "\n";
+    // A shared temp dir may serve several sessions, so disambiguate by process there.
+    let tmp = locator.tmp_dir.as_deref().map_or_else(
+        || session_dir.join(format!("_session.json.tmp.{owner}")),
+        |tmp_dir| tmp_dir.join(format!("_session.json.tmp.{owner}.{}", std::process::id())),
+    );
+    let body = session_file_body(session)?;
     fs::write(&tmp, body).with_context(|| format!("write temp session file {}", tmp.display()))?;
 
     // Best-effort cross-platform replacement:
@@ -876,7 +2132,11 @@ fn write_session_file_atomic(
     Ok(())
 }
 
-fn validate_id8(id8: &str, label: &str) -> anyhow::Result<()> {
+/// Check that `id8` is exactly 8 ASCII alphanumeric characters; `label` names it in errors.
+///
+/// # Errors
+/// Returns an error describing why the id is invalid.
+pub fn validate_id8(id8: &str, label: &str) -> anyhow::Result<()> {
     if id8.len() != 8 {
         return Err(anyhow::anyhow!("{label} must be 8 characters"));
     }
@@ -894,13 +2154,22 @@ fn validate_id8(id8: &str, label: &str) -> anyhow::Result<()> {
 pub struct SessionLocator {
     /// Path to the session directory.
     pub session_dir: PathBuf,
+    /// Directory atomic `_session.json` writes are staged in instead of the session directory
+    /// (set through [`SessionLocator::with_tmp_dir`], which verifies it).
+    pub tmp_dir: Option<PathBuf>,
+    /// Also take the advisory filesystem lock ([`LockConfig::fs_lock`]) while mutating.
+    pub fs_lock: bool,
 }
 
 impl SessionLocator {
     /// Create a new locator from an explicit session directory path.
     #[must_use]
     pub const fn new(session_dir: PathBuf) -> Self {
-        Self { session_dir }
+        Self {
+            session_dir,
+            tmp_dir: None,
+            fs_lock: false,
+        }
     }
 
     /// Compute the session directory from `repo_root` and `session_date`.
@@ -909,6 +2178,46 @@ impl SessionLocator {
         let p = paths::session_paths(repo_root, session_date);
         Self {
             session_dir: p.session_dir,
+            tmp_dir: None,
+            fs_lock: false,
+        }
+    }
+
+    /// Stage atomic `_session.json` writes in `dir` instead of the session directory.
+    ///
+    /// The rename that publishes `_session.json` is only atomic within one filesystem, so `dir`
+    /// is kept only when it is verified to be on the same device as the session directory (or,
+    /// before that exists, its nearest existing ancestor). Otherwise writes stay in the session
+    /// directory and a [`WarningCode::TmpDirFallback`] warning is pushed to `warnings`.
+    #[must_use]
+    pub fn with_tmp_dir(mut self, dir: &Path, warnings: &mut Warnings) -> Self {
+        let anchor = self
+            .session_dir
+            .ancestors()
+            .find(|p| p.exists())
+            .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+        let (tmp_dir, warning) = choose_tmp_dir(&anchor, dir, same_device);
+        self.tmp_dir = tmp_dir;
+        if let Some(warning) = warning {
+            warnings.push(warning);
+        }
+        self
+    }
+
+    /// Take the advisory filesystem lock alongside the lock file on every mutation (see
+    /// [`crate::lock`]), for session dirs on filesystems where exclusive create is unreliable.
+    #[must_use]
+    pub const fn with_fs_lock(mut self, fs_lock: bool) -> Self {
+        self.fs_lock = fs_lock;
+        self
+    }
+
+    /// Lock configuration used by mutations of this session.
+    #[must_use]
+    pub fn lock_config(&self) -> LockConfig {
+        LockConfig {
+            fs_lock: self.fs_lock,
+            ..LockConfig::default()
         }
     }
 
@@ -923,6 +2232,12 @@ impl SessionLocator {
     pub fn session_file(&self) -> PathBuf {
         session_file_path(&self.session_dir)
     }
+
+    /// Compute the full path to `_session.json.lock` inside this session directory.
+    #[must_use]
+    pub fn lock_file(&self) -> PathBuf {
+        lock::lock_file_path(&self.session_dir)
+    }
 }
 
 #[cfg(test)]
@@ -954,6 +2269,8 @@ mod tests {
             updated_at: "2026-01-11T01:00:00Z".to_string(),
             finished_at: Some("2026-01-11T02:00:00Z".to_string()),
             current_phase: Some(ReviewPhase::ReportWriting),
+            eta: None,
+            blocking: None,
             verdict: Some(ReviewVerdict::Approve),
             counts: SeverityCounts::zero(),
             report_file: Some(
@@ -965,10 +2282,37 @@ mod tests {
                 timestamp: "2026-01-11T01:30:00Z".to_string(),
                 note_type: NoteType::Question,
                 content: Value::String("context".to_string()),
+                tags: Vec::new(),
+                severity: None,
             }],
+            status_history: Vec::new(),
         }
     }
 
+    #[test]
+    fn choose_tmp_dir_falls_back_unless_same_device() -> anyhow::Result<()> {
+        let session_dir = Path::new("/sessions/2026-01-11");
+        let requested = Path::new("/fast/tmp");
+
+        let (dir, warning) = choose_tmp_dir(session_dir, requested, |_, _| Ok(true));
+        ensure!(dir.as_deref() == Some(requested) && warning.is_none());
+
+        let (dir, warning) = choose_tmp_dir(session_dir, requested, |_, _| Ok(false));
+        ensure!(dir.is_none());
+        let Some(warning) = warning else {
+            bail!("cross-device override should warn");
+        };
+        ensure!(warning.code == WarningCode::TmpDirFallback);
+        ensure!(warning.message.contains("different device"));
+
+        let (dir, warning) = choose_tmp_dir(session_dir, requested, |_, _| {
+            Err(anyhow::anyhow!("stat /fast/tmp: not found"))
+        });
+        ensure!(dir.is_none());
+        ensure!(warning.is_some_and(|w| w.message.contains("not found")));
+        Ok(())
+    }
+
     #[test]
     fn reports_filters_match_status_phase_verdict() -> anyhow::Result<()> {
         let entry = make_entry();
@@ -982,8 +2326,9 @@ mod tests {
             phases: vec![ReviewPhase::ReportWriting],
             only_with_report: true,
             only_with_notes: true,
+            ..ReportsFilters::default()
         };
-        ensure!(filters.matches(&entry));
+        ensure!(filters.matches(&entry, OffsetDateTime::now_utc())?);
 
         let mismatched = ReportsFilters {
             target_ref: None,
@@ -995,9 +2340,159 @@ mod tests {
             phases: Vec::new(),
             only_with_report: false,
             only_with_notes: false,
+            ..ReportsFilters::default()
+        };
+        ensure!(!mismatched.matches(&entry, OffsetDateTime::now_utc())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn severity_counts_min_severity_thresholds() -> anyhow::Result<()> {
+        let counts = |blocker, major, minor, nit| SeverityCounts {
+            blocker,
+            major,
+            minor,
+            nit,
+        };
+        let none = SeverityCounts::zero();
+        let nits = counts(0, 0, 0, 3);
+        let minors = counts(0, 0, 2, 1);
+        let majors = counts(0, 1, 0, 0);
+        let blockers = counts(1, 0, 0, 0);
+
+        ensure!(none.max_severity().is_none());
+        ensure!(nits.max_severity() == Some(Severity::Nit));
+        ensure!(blockers.max_severity() == Some(Severity::Blocker));
+
+        for (min, expected) in [
+            (Severity::Nit, [false, true, true, true, true]),
+            (Severity::Minor, [false, false, true, true, true]),
+            (Severity::Major, [false, false, false, true, true]),
+            (Severity::Blocker, [false, false, false, false, true]),
+        ] {
+            let actual = [&none, &nits, &minors, &majors, &blockers].map(|c| c.has_at_least(min));
+            ensure!(
+                actual == expected,
+                "min {min:?}: {actual:?} != {expected:?}"
+            );
+        }
+
+        let mut entry = make_entry();
+        entry.counts = nits;
+        let filters = ReportsFilters {
+            min_severity: Some(Severity::Major),
+            ..ReportsFilters::default()
+        };
+        ensure!(!filters.matches(&entry, OffsetDateTime::now_utc())?);
+        entry.counts = majors;
+        ensure!(filters.matches(&entry, OffsetDateTime::now_utc())?);
+        Ok(())
+    }
+
+    #[test]
+    fn session_locator_lock_file_sits_next_to_session_file() -> anyhow::Result<()> {
+        let locator = SessionLocator::new(PathBuf::from("/tmp/reviews/2026-01-11"));
+        ensure!(locator.lock_file() == Path::new("/tmp/reviews/2026-01-11/_session.json.lock"));
+        ensure!(locator.lock_file().parent() == locator.session_file().parent());
+        Ok(())
+    }
+
+    #[test]
+    fn glob_match_wildcards() -> anyhow::Result<()> {
+        ensure!(glob_match("feat/*", "feat/x"));
+        ensure!(glob_match("feat/*", "feat/"));
+        ensure!(glob_match("feat/*", "feat/a/b"));
+        ensure!(!glob_match("feat/*", "fix/y"));
+        ensure!(glob_match("*", ""));
+        ensure!(glob_match("release/?.0", "release/2.0"));
+        ensure!(!glob_match("release/?.0", "release/10.0"));
+        ensure!(glob_match("*main*", "refs/heads/main"));
+        ensure!(glob_match("a*b*c", "axxbyyc"));
+        ensure!(!glob_match("a*b*c", "axxbyy"));
+        ensure!(glob_match("main", "main"));
+        ensure!(!glob_match("main", "main2"));
+        Ok(())
+    }
+
+    #[test]
+    fn reports_filters_target_ref_glob() -> anyhow::Result<()> {
+        let mut entry = make_entry();
+        let filters = ReportsFilters {
+            target_ref_glob: Some("feat/*".to_string()),
+            ..ReportsFilters::default()
+        };
+        entry.target_ref = "feat/x".to_string();
+        ensure!(filters.matches(&entry, OffsetDateTime::now_utc())?);
+        entry.target_ref = "fix/y".to_string();
+        ensure!(!filters.matches(&entry, OffsetDateTime::now_utc())?);
+        Ok(())
+    }
+
+    #[test]
+    fn register_reviewer_strict_ref_rejects_newlines() -> anyhow::Result<()> {
+        let repo_root = tempdir()?;
+        let now = OffsetDateTime::now_utc();
+        let session_date = now.date();
+        let session = SessionLocator::from_repo_root(repo_root.path(), session_date);
+        let params = |strict_ref| RegisterReviewerParams {
+            repo_root: repo_root.path().to_path_buf(),
+            session_date,
+            session: session.clone(),
+            target_ref: "refs/heads/main\n".to_string(),
+            reviewer_id: Some("deadbeef".to_string()),
+            session_id: Some("sess0001".to_string()),
+            parent_id: None,
+            strict_ref,
+            max_reviewers: None,
+            observe_only: false,
+            require_active_session: false,
+            now,
+        };
+
+        let Err(err) = register_reviewer(params(true)) else {
+            bail!("expected strict_ref to reject a newline");
+        };
+        ensure!(err.to_string().contains("control characters"));
+        ensure!(!session.session_file().exists());
+
+        let res = register_reviewer(params(false))?;
+        ensure!(res.reviewer_id == "deadbeef");
+        Ok(())
+    }
+
+    #[test]
+    fn register_reviewer_max_reviewers_caps_new_reviewers_only() -> anyhow::Result<()> {
+        let repo_root = tempdir()?;
+        let now = OffsetDateTime::now_utc();
+        let session_date = now.date();
+        let session = SessionLocator::from_repo_root(repo_root.path(), session_date);
+        let params = |reviewer_id: &str| RegisterReviewerParams {
+            repo_root: repo_root.path().to_path_buf(),
+            session_date,
+            session: session.clone(),
+            target_ref: "refs/heads/main".to_string(),
+            reviewer_id: Some(reviewer_id.to_string()),
+            session_id: Some("sess0001".to_string()),
+            parent_id: None,
+            strict_ref: false,
+            max_reviewers: Some(2),
+            observe_only: false,
+            require_active_session: false,
+            now,
         };
-        ensure!(!mismatched.matches(&entry));
 
+        register_reviewer(params("deadbeef"))?;
+        register_reviewer(params("cafebabe"))?;
+        let Err(err) = register_reviewer(params("feedface")) else {
+            bail!("expected the third reviewer to exceed max_reviewers");
+        };
+        ensure!(err.to_string().contains("max_reviewers is 2"));
+        ensure!(preview_register_reviewer(params("feedface")).is_err());
+
+        let res = register_reviewer(params("cafebabe"))?;
+        ensure!(res.reviewer_id == "cafebabe");
+        ensure!(load_session(&session)?.reviews.len() == 2);
         Ok(())
     }
 
@@ -1017,6 +2512,10 @@ mod tests {
             reviewer_id: Some("deadbeef".to_string()),
             session_id: Some("sess0001".to_string()),
             parent_id: None,
+            strict_ref: false,
+            max_reviewers: None,
+            observe_only: false,
+            require_active_session: false,
             now,
         })?;
 
@@ -1028,6 +2527,10 @@ mod tests {
             reviewer_id: Some("deadbeef".to_string()),
             session_id: Some("sess0001".to_string()),
             parent_id: None,
+            strict_ref: false,
+            max_reviewers: None,
+            observe_only: false,
+            require_active_session: false,
             now,
         });
         let Err(err) = result else {
@@ -1056,6 +2559,8 @@ mod tests {
             session_id: "sess0001".to_string(),
             status: Some(ReviewerStatus::InProgress),
             phase: None,
+            eta: None,
+            bump_only: false,
             now: OffsetDateTime::now_utc(),
         };
         let Err(err) = update_review(&params) else {
@@ -1080,10 +2585,13 @@ mod tests {
             updated_at: "2026-01-11T01:00:00Z".to_string(),
             finished_at: Some("2026-01-11T02:00:00Z".to_string()),
             current_phase: Some(ReviewPhase::ReportWriting),
+            eta: None,
+            blocking: None,
             verdict: Some(ReviewVerdict::Approve),
             counts: SeverityCounts::zero(),
             report_file: Some("existing.md".to_string()),
             notes: Vec::new(),
+            status_history: Vec::new(),
         };
         let session = SessionFile {
             schema_version: "1.0.0".to_string(),
@@ -1100,8 +2608,14 @@ mod tests {
             session_id: "sess0001".to_string(),
             verdict: ReviewVerdict::Approve,
             counts: SeverityCounts::zero(),
-            report_markdown: "report\n".to_string(),
+            report_markdown: Some("report\n".to_string()),
             now: OffsetDateTime::now_utc(),
+            update_if_finished: false,
+            report_ext: DEFAULT_REPORT_EXT.to_string(),
+            allow_empty_report: false,
+            initiator_status: None,
+            template: None,
+            cleanup_on_failure: false,
         };
         let Err(err) = finalize_review(params) else {
             bail!("should refuse overwrite");
@@ -1125,10 +2639,13 @@ mod tests {
             updated_at: "2026-01-11T01:00:00Z".to_string(),
             finished_at: None,
             current_phase: None,
+            eta: None,
+            blocking: None,
             verdict: None,
             counts: SeverityCounts::zero(),
             report_file: None,
             notes: Vec::new(),
+            status_history: Vec::new(),
         };
         let session = SessionFile {
             schema_version: "1.0.0".to_string(),
@@ -1148,6 +2665,12 @@ mod tests {
             content: Value::String("why?".to_string()),
             now: OffsetDateTime::now_utc(),
             lock_owner: "bad".to_string(),
+            dedupe: false,
+            max_notes: None,
+            archive_pruned: false,
+            tags: Vec::new(),
+            severity: None,
+            initiator_status: None,
         };
         let Err(err) = append_note(params) else {
             bail!("bad lock_owner should error");
@@ -1268,10 +2791,53 @@ pub struct RegisterReviewerParams {
     pub session_id: Option<String>,
     /// Optional parent reviewer id (id8) for handoff/chaining.
     pub parent_id: Option<String>,
+    /// Reject target refs containing control characters (newlines, tabs, etc) before writing.
+    pub strict_ref: bool,
+    /// Refuse to add a new reviewer once the resolved session already has this many distinct
+    /// reviewers (re-registering an existing entry is always allowed).
+    pub max_reviewers: Option<usize>,
+    /// Register a non-blocking observer entry (`blocking: false`) that waits ignore.
+    pub observe_only: bool,
+    /// Only join: refuse unless `_session.json` already holds an active session for
+    /// `target_ref` (checked under the lock), so no session is started or created here.
+    pub require_active_session: bool,
     /// Timestamp used for `started_at` / `updated_at`.
     pub now: OffsetDateTime,
 }
 
+fn validate_target_ref_strict(target_ref: &str) -> anyhow::Result<()> {
+    if target_ref.chars().any(char::is_control) {
+        return Err(anyhow::anyhow!(
+            "invalid target_ref {target_ref:?}: contains control characters or newlines"
+        ));
+    }
+    Ok(())
+}
+
+fn check_reviewer_cap(
+    session: Option<&SessionFile>,
+    session_id: &str,
+    reviewer_id: &str,
+    max_reviewers: Option<usize>,
+) -> anyhow::Result<()> {
+    let Some(max) = max_reviewers else {
+        return Ok(());
+    };
+    let reviewers: std::collections::BTreeSet<&str> = session
+        .into_iter()
+        .flat_map(|session| &session.reviews)
+        .filter(|r| r.session_id == session_id)
+        .map(|r| r.reviewer_id.as_str())
+        .collect();
+    if !reviewers.contains(reviewer_id) && reviewers.len() >= max {
+        return Err(anyhow::anyhow!(
+            "session_id {session_id} already has {} reviewer(s); max_reviewers is {max}",
+            reviewers.len()
+        ));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize)]
 /// Result returned by [`register_reviewer`].
 pub struct RegisterReviewerResult {
@@ -1283,31 +2849,212 @@ pub struct RegisterReviewerResult {
     pub session_dir: String,
     /// Session file path as a string.
     pub session_file: String,
+    /// Whether `session_id` already had entries in `_session.json` (joined an existing session).
+    pub joined_existing_session: bool,
+    /// Whether this result is a preview from [`preview_register_reviewer`] (nothing was written).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// Where the eventual report is expected to land (see [`report_hint`]); only set on request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_hint: Option<ReportHint>,
 }
 
-/// Register a reviewer in the session file.
-///
-/// This creates the session directory and `_session.json` if needed, adds the reviewer to the
-/// `reviewers` list (if missing), and appends a new entry in `reviews` unless one already exists
-/// for the same `(reviewer_id, session_id)`.
-///
-/// # Errors
-/// Returns an error if identifiers are invalid, the session cannot be read or written,
-/// or the lock cannot be acquired.
-#[allow(clippy::too_many_lines)]
-pub fn register_reviewer(params: RegisterReviewerParams) -> anyhow::Result<RegisterReviewerResult> {
-    let reviewer_id = match params.reviewer_id {
-        Some(reviewer_id) => reviewer_id,
-        None => id::random_id8()?,
-    };
-    validate_id8(&reviewer_id, "reviewer_id")?;
+#[derive(Debug, Clone, Serialize)]
+/// Expected location of a reviewer's report, before `started_at` fixes the exact filename.
+pub struct ReportHint {
+    /// Session directory the report will be written to.
+    pub session_dir: String,
+    /// Report filename with [`REPORT_TIME_PLACEHOLDER`] standing in for the time prefix.
+    pub report_file_pattern: String,
+}
 
-    if let Some(ref parent_id) = params.parent_id {
-        validate_id8(parent_id, "parent_id")?;
+/// Build a [`ReportHint`] for a reviewer registered in `session_dir` (default extension).
+#[must_use]
+pub fn report_hint(session_dir: &str, target_ref: &str, reviewer_id: &str) -> ReportHint {
+    ReportHint {
+        session_dir: session_dir.to_string(),
+        report_file_pattern: format_report_file_name(
+            REPORT_TIME_PLACEHOLDER,
+            target_ref,
+            reviewer_id,
+            DEFAULT_REPORT_EXT,
+        ),
     }
+}
 
-    fs::create_dir_all(params.session.session_dir()).with_context(|| {
-        format!(
+fn reviewer_id_taken(session: Option<&SessionFile>, reviewer_id: &str) -> bool {
+    session.is_some_and(|session| session.reviewers.iter().any(|r| r == reviewer_id))
+}
+
+/// The session id that registration for `target_ref` would join: that of the first review
+/// for `target_ref` whose reviewer is still active (`INITIALIZING`, `IN_PROGRESS`, `BLOCKED`,
+/// `PAUSED`).
+#[must_use]
+pub fn active_session_id<'a>(session: &'a SessionFile, target_ref: &str) -> Option<&'a str> {
+    session
+        .reviews
+        .iter()
+        .find(|r| {
+            r.target_ref == target_ref
+                && matches!(
+                    r.status,
+                    ReviewerStatus::Initializing
+                        | ReviewerStatus::InProgress
+                        | ReviewerStatus::Blocked
+                        | ReviewerStatus::Paused
+                )
+        })
+        .map(|r| r.session_id.as_str())
+}
+
+fn resolve_register_session_id(
+    session: Option<&SessionFile>,
+    target_ref: &str,
+    session_id: Option<String>,
+) -> anyhow::Result<String> {
+    if let Some(session_id) = session_id {
+        validate_id8(&session_id, "session_id")?;
+        return Ok(session_id);
+    }
+    // Join active session if one exists for this target_ref.
+    let active_session = session.and_then(|session| active_session_id(session, target_ref));
+    // A freshly minted id must not alias any session already in the file.
+    active_session.map_or_else(
+        || {
+            id::random_id8_excluding(|id| {
+                session.is_some_and(|session| session.reviews.iter().any(|r| r.session_id == id))
+            })
+        },
+        |session_id| Ok(session_id.to_string()),
+    )
+}
+
+/// Enforce [`RegisterReviewerParams::require_active_session`]; `session` is `None` when there is
+/// no session file yet.
+fn require_active_session(
+    session: Option<&SessionFile>,
+    target_ref: &str,
+    session_dir: &Path,
+) -> anyhow::Result<()> {
+    if session
+        .and_then(|session| active_session_id(session, target_ref))
+        .is_none()
+    {
+        return Err(anyhow::anyhow!(
+            "no active session for target_ref {target_ref:?} in {}; refusing to start one there",
+            session_dir.display()
+        ));
+    }
+    Ok(())
+}
+
+fn session_has_entries(session: Option<&SessionFile>, session_id: &str) -> bool {
+    session.is_some_and(|session| session.reviews.iter().any(|r| r.session_id == session_id))
+}
+
+/// Preview [`register_reviewer`] without creating directories, taking the lock, or writing.
+///
+/// Ids are resolved exactly as registration would resolve them (random ids are generated
+/// fresh, so pass explicit ids for a stable preview).
+///
+/// # Errors
+/// Returns an error if identifiers are invalid or an existing session file cannot be read,
+/// or if the existing `(reviewer_id, session_id)` entry targets a different ref.
+pub fn preview_register_reviewer(
+    params: RegisterReviewerParams,
+) -> anyhow::Result<RegisterReviewerResult> {
+    if let Some(ref reviewer_id) = params.reviewer_id {
+        validate_id8(reviewer_id, "reviewer_id")?;
+    }
+
+    if let Some(ref parent_id) = params.parent_id {
+        validate_id8(parent_id, "parent_id")?;
+    }
+    if params.strict_ref {
+        validate_target_ref_strict(&params.target_ref)?;
+    }
+
+    let session = if params.session.session_file().exists() {
+        Some(read_session_file(params.session.session_dir())?)
+    } else {
+        None
+    };
+    if params.require_active_session {
+        require_active_session(
+            session.as_ref(),
+            &params.target_ref,
+            params.session.session_dir(),
+        )?;
+    }
+    let reviewer_id = match params.reviewer_id {
+        Some(reviewer_id) => reviewer_id,
+        None => id::random_id8_excluding(|id| reviewer_id_taken(session.as_ref(), id))?,
+    };
+    let session_id =
+        resolve_register_session_id(session.as_ref(), &params.target_ref, params.session_id)?;
+
+    if let Some(existing) = session.as_ref().and_then(|session| {
+        session
+            .reviews
+            .iter()
+            .find(|r| r.reviewer_id == reviewer_id && r.session_id == session_id)
+    }) {
+        if existing.target_ref != params.target_ref {
+            return Err(anyhow::anyhow!(
+                "review entry already exists for reviewer_id/session_id but target_ref differs"
+            ));
+        }
+    }
+    check_reviewer_cap(
+        session.as_ref(),
+        &session_id,
+        &reviewer_id,
+        params.max_reviewers,
+    )?;
+
+    Ok(RegisterReviewerResult {
+        joined_existing_session: session_has_entries(session.as_ref(), &session_id),
+        reviewer_id,
+        session_id,
+        session_dir: params.session.session_dir().to_string_lossy().to_string(),
+        session_file: params.session.session_file().to_string_lossy().to_string(),
+        dry_run: true,
+        report_hint: None,
+    })
+}
+
+/// Register a reviewer in the session file.
+///
+/// This creates the session directory and `_session.json` if needed, adds the reviewer to the
+/// `reviewers` list (if missing), and appends a new entry in `reviews` unless one already exists
+/// for the same `(reviewer_id, session_id)`.
+///
+/// # Errors
+/// Returns an error if identifiers are invalid, adding the reviewer would exceed
+/// [`RegisterReviewerParams::max_reviewers`], [`RegisterReviewerParams::require_active_session`]
+/// is set but no active session exists, the session cannot be read or written, or the lock
+/// cannot be acquired.
+#[allow(clippy::too_many_lines)]
+pub fn register_reviewer(params: RegisterReviewerParams) -> anyhow::Result<RegisterReviewerResult> {
+    let random_reviewer_id = params.reviewer_id.is_none();
+    let mut reviewer_id = match params.reviewer_id {
+        Some(reviewer_id) => reviewer_id,
+        None => id::random_id8()?,
+    };
+    validate_id8(&reviewer_id, "reviewer_id")?;
+
+    if let Some(ref parent_id) = params.parent_id {
+        validate_id8(parent_id, "parent_id")?;
+    }
+    if params.strict_ref {
+        validate_target_ref_strict(&params.target_ref)?;
+    }
+    if params.require_active_session && !params.session.session_file().exists() {
+        require_active_session(None, &params.target_ref, params.session.session_dir())?;
+    }
+
+    fs::create_dir_all(params.session.session_dir()).with_context(|| {
+        format!(
             "create session dir {}",
             params.session.session_dir().display()
         )
@@ -1317,44 +3064,30 @@ pub fn register_reviewer(params: RegisterReviewerParams) -> anyhow::Result<Regis
     let _guard = lock::acquire_lock(
         params.session.session_dir(),
         lock_owner,
-        LockConfig::default(),
+        params.session.lock_config(),
     )?;
 
     let mut session = if params.session.session_file().exists() {
         read_session_file(params.session.session_dir())?
     } else {
-        let repo_root = params
-            .repo_root
-            .canonicalize()
-            .with_context(|| format!("canonicalize repo_root {}", params.repo_root.display()))?;
-        SessionFile {
-            schema_version: "1.0.0".to_string(),
-            session_date: params.session_date.to_string(),
-            repo_root: repo_root.to_string_lossy().to_string(),
-            reviewers: vec![],
-            reviews: vec![],
-        }
+        new_session_file(&params.repo_root, params.session_date)?
     };
+    if params.require_active_session {
+        require_active_session(
+            Some(&session),
+            &params.target_ref,
+            params.session.session_dir(),
+        )?;
+    }
 
-    let session_id = if let Some(session_id) = params.session_id {
-        validate_id8(&session_id, "session_id")?;
-        session_id
-    } else {
-        // Join active session if one exists for this target_ref.
-        let active_session = session.reviews.iter().find(|r| {
-            r.target_ref == params.target_ref
-                && matches!(
-                    r.status,
-                    ReviewerStatus::Initializing
-                        | ReviewerStatus::InProgress
-                        | ReviewerStatus::Blocked
-                )
-        });
-        match active_session {
-            Some(r) => r.session_id.clone(),
-            None => id::random_id8()?,
-        }
-    };
+    // The random id doubled as the lock owner; re-roll it if it aliases a registered reviewer.
+    if random_reviewer_id && reviewer_id_taken(Some(&session), &reviewer_id) {
+        reviewer_id = id::random_id8_excluding(|id| reviewer_id_taken(Some(&session), id))?;
+    }
+
+    let session_id =
+        resolve_register_session_id(Some(&session), &params.target_ref, params.session_id)?;
+    let joined_existing_session = session_has_entries(Some(&session), &session_id);
 
     if let Some(existing) = session
         .reviews
@@ -1369,7 +3102,7 @@ pub fn register_reviewer(params: RegisterReviewerParams) -> anyhow::Result<Regis
 
         if !session.reviewers.iter().any(|r| r == &reviewer_id) {
             session.reviewers.push(reviewer_id.clone());
-            write_session_file_atomic(params.session.session_dir(), &reviewer_id, &session)?;
+            write_session_file_atomic(&params.session, &reviewer_id, &session)?;
         }
 
         return Ok(RegisterReviewerResult {
@@ -1377,9 +3110,19 @@ pub fn register_reviewer(params: RegisterReviewerParams) -> anyhow::Result<Regis
             session_id,
             session_dir: params.session.session_dir().to_string_lossy().to_string(),
             session_file: params.session.session_file().to_string_lossy().to_string(),
+            joined_existing_session,
+            dry_run: false,
+            report_hint: None,
         });
     }
 
+    check_reviewer_cap(
+        Some(&session),
+        &session_id,
+        &reviewer_id,
+        params.max_reviewers,
+    )?;
+
     let initiator_status = session
         .reviews
         .iter()
@@ -1405,19 +3148,25 @@ pub fn register_reviewer(params: RegisterReviewerParams) -> anyhow::Result<Regis
         updated_at: started_at,
         finished_at: None,
         current_phase: None,
+        eta: None,
+        blocking: params.observe_only.then_some(false),
         verdict: None,
         counts: SeverityCounts::zero(),
         report_file: None,
         notes: vec![],
+        status_history: Vec::new(),
     });
 
-    write_session_file_atomic(params.session.session_dir(), &reviewer_id, &session)?;
+    write_session_file_atomic(&params.session, &reviewer_id, &session)?;
 
     Ok(RegisterReviewerResult {
         reviewer_id,
         session_id,
         session_dir: params.session.session_dir().to_string_lossy().to_string(),
         session_file: params.session.session_file().to_string_lossy().to_string(),
+        joined_existing_session,
+        dry_run: false,
+        report_hint: None,
     })
 }
 
@@ -1434,58 +3183,526 @@ pub struct UpdateReviewParams {
     pub status: Option<ReviewerStatus>,
     /// If set, update `current_phase` (use `Some(None)` to clear).
     pub phase: Option<Option<ReviewPhase>>,
+    /// If set, update `eta` (use `Some(None)` to clear).
+    pub eta: Option<Option<OffsetDateTime>>,
+    /// Allow an update that sets none of `status`/`phase`/`eta`, which then only refreshes
+    /// `updated_at`.
+    pub bump_only: bool,
     /// Timestamp written to `updated_at`.
     pub now: OffsetDateTime,
 }
 
-/// Update a review entry's reviewer-owned `status` and/or `current_phase`.
+/// Update a review entry's reviewer-owned `status`, `current_phase`, and/or `eta`.
 ///
 /// # Errors
-/// Returns an error if identifiers are invalid, the session cannot be read or written,
-/// or the lock cannot be acquired.
+/// Returns an error if identifiers are invalid, nothing would be updated and `bump_only` is not
+/// set, the session cannot be read or written, or the lock cannot be acquired.
 pub fn update_review(params: &UpdateReviewParams) -> anyhow::Result<()> {
     validate_id8(&params.reviewer_id, "reviewer_id")?;
     validate_id8(&params.session_id, "session_id")?;
+    if !params.bump_only
+        && params.status.is_none()
+        && params.phase.is_none()
+        && params.eta.is_none()
+    {
+        return Err(anyhow::anyhow!(
+            "nothing to update (pass --status/--phase/--eta, or --bump-only to refresh updated_at)"
+        ));
+    }
+
+    let lock_owner = params.reviewer_id.clone();
+    let _guard = lock::acquire_lock(
+        params.session.session_dir(),
+        lock_owner,
+        params.session.lock_config(),
+    )?;
+
+    let mut session = read_session_file(params.session.session_dir())?;
+
+    let entry = session
+        .reviews
+        .iter_mut()
+        .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
+        .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
+
+    if let Some(status) = params.status {
+        entry.status = status;
+    }
+    if let Some(phase) = params.phase {
+        entry.current_phase = phase;
+    }
+    if let Some(eta) = params.eta {
+        entry.eta = eta
+            .map(|eta| format_ts(eta.to_offset(time::UtcOffset::UTC)))
+            .transpose()?;
+    }
+    entry.updated_at = format_ts(params.now)?;
+
+    write_session_file_atomic(&params.session, &params.reviewer_id, &session)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`touch_review`].
+pub struct TouchReviewParams {
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Reviewer id for the entry being touched (id8).
+    pub reviewer_id: String,
+    /// Session id for the entry being touched (id8).
+    pub session_id: String,
+    /// Timestamp written to `updated_at`.
+    pub now: OffsetDateTime,
+}
+
+/// Refresh a review entry's `updated_at` without changing any other field.
+///
+/// # Errors
+/// Returns an error if identifiers are invalid, the entry is missing or terminal,
+/// the session cannot be read or written, or the lock cannot be acquired.
+pub fn touch_review(params: &TouchReviewParams) -> anyhow::Result<()> {
+    validate_id8(&params.reviewer_id, "reviewer_id")?;
+    validate_id8(&params.session_id, "session_id")?;
+
+    let lock_owner = params.reviewer_id.clone();
+    let _guard = lock::acquire_lock(
+        params.session.session_dir(),
+        lock_owner,
+        params.session.lock_config(),
+    )?;
+
+    let mut session = read_session_file(params.session.session_dir())?;
+
+    let entry = session
+        .reviews
+        .iter_mut()
+        .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
+        .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
+
+    if entry.status.is_terminal() {
+        return Err(anyhow::anyhow!(
+            "review entry is terminal ({}); refusing to touch",
+            wire_name(&entry.status)
+        ));
+    }
+    entry.updated_at = format_ts(params.now)?;
+
+    write_session_file_atomic(&params.session, &params.reviewer_id, &session)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`rename_target`].
+pub struct RenameTargetParams {
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Target ref to replace (exact match).
+    pub from: String,
+    /// Replacement target ref.
+    pub to: String,
+    /// Timestamp written to `updated_at` on renamed entries.
+    pub now: OffsetDateTime,
+    /// Lock owner identifier used while updating `_session.json`.
+    pub lock_owner: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result returned by [`rename_target`].
+pub struct RenameTargetResult {
+    /// Number of review entries whose `target_ref` was rewritten.
+    pub renamed: usize,
+}
+
+/// Rewrite `target_ref` from `from` to `to` on every matching review entry.
+///
+/// All matching entries are updated in a single atomic write. Entries are never merged, and
+/// session ids, statuses, and existing report file names are left untouched.
+///
+/// # Errors
+/// Returns an error if `to` is empty or equal to `from`, the session cannot be read or written,
+/// or the lock cannot be acquired.
+pub fn rename_target(params: &RenameTargetParams) -> anyhow::Result<RenameTargetResult> {
+    validate_id8(&params.lock_owner, "lock_owner")?;
+    if params.to.trim().is_empty() {
+        return Err(anyhow::anyhow!("--to must not be empty"));
+    }
+    if params.to == params.from {
+        return Err(anyhow::anyhow!(
+            "--to is the same as --from ({:?}); nothing to rename",
+            params.from
+        ));
+    }
+
+    let _guard = lock::acquire_lock(
+        params.session.session_dir(),
+        params.lock_owner.clone(),
+        params.session.lock_config(),
+    )?;
+
+    let mut session = read_session_file(params.session.session_dir())?;
+    let updated_at = format_ts(params.now)?;
+    let mut renamed = 0_usize;
+    for entry in session
+        .reviews
+        .iter_mut()
+        .filter(|r| r.target_ref == params.from)
+    {
+        entry.target_ref.clone_from(&params.to);
+        entry.updated_at.clone_from(&updated_at);
+        renamed += 1;
+    }
+
+    if renamed > 0 {
+        write_session_file_atomic(&params.session, &params.lock_owner, &session)?;
+    }
+    Ok(RenameTargetResult { renamed })
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`close_stale`].
+pub struct CloseStaleParams {
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Close non-terminal reviews whose `updated_at` is more than this many seconds old.
+    pub older_than_secs: u64,
+    /// Report the matching entries without taking the lock or writing.
+    pub dry_run: bool,
+    /// Reference time for staleness, and the timestamp written to closed entries.
+    pub now: OffsetDateTime,
+    /// Lock owner id8 used while updating `_session.json`.
+    pub lock_owner: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// A review entry closed (or, in a dry run, that would be closed) by [`close_stale`].
+pub struct StaleReview {
+    /// Reviewer id (id8).
+    pub reviewer_id: String,
+    /// Session id (id8).
+    pub session_id: String,
+    /// Target ref under review.
+    pub target_ref: String,
+    /// Reviewer status before closing.
+    pub status: ReviewerStatus,
+    /// `updated_at` before closing.
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result returned by [`close_stale`].
+pub struct CloseStaleResult {
+    /// Whether this was a dry run (nothing was written).
+    pub dry_run: bool,
+    /// Entries that were (or would be) transitioned to `ERROR`.
+    pub closed: Vec<StaleReview>,
+}
+
+/// Transition every non-terminal review idle for longer than `older_than_secs` to `ERROR`.
+///
+/// Each closed entry gets `finished_at`/`updated_at` set to `now` and a reviewer `error_detail`
+/// note explaining why, so abandoned reviews no longer hold up the session. All changes land in a
+/// single atomic write; a dry run only reads the session.
+///
+/// # Errors
+/// Returns an error if the lock owner is invalid, an open entry has an unparseable `updated_at`,
+/// the session cannot be read or written, or the lock cannot be acquired.
+pub fn close_stale(params: &CloseStaleParams) -> anyhow::Result<CloseStaleResult> {
+    validate_id8(&params.lock_owner, "lock_owner")?;
+    let threshold = time::Duration::seconds(i64::try_from(params.older_than_secs)?);
+
+    let guard = if params.dry_run {
+        None
+    } else {
+        Some(lock::acquire_lock(
+            params.session.session_dir(),
+            params.lock_owner.clone(),
+            params.session.lock_config(),
+        )?)
+    };
+    let mut session = read_session_file(params.session.session_dir())?;
+    let timestamp = format_ts(params.now)?;
+    let mut closed = Vec::new();
+    for entry in &mut session.reviews {
+        if entry.status.is_terminal() {
+            continue;
+        }
+        let updated_at = parse_ts(&entry.updated_at).with_context(|| {
+            format!(
+                "parse updated_at for reviewer_id={} session_id={}",
+                entry.reviewer_id, entry.session_id
+            )
+        })?;
+        if params.now - updated_at <= threshold {
+            continue;
+        }
+        closed.push(StaleReview {
+            reviewer_id: entry.reviewer_id.clone(),
+            session_id: entry.session_id.clone(),
+            target_ref: entry.target_ref.clone(),
+            status: entry.status,
+            updated_at: entry.updated_at.clone(),
+        });
+        if params.dry_run {
+            continue;
+        }
+        entry.notes.push(SessionNote {
+            role: NoteRole::Reviewer,
+            timestamp: timestamp.clone(),
+            note_type: NoteType::ErrorDetail,
+            content: Value::String(format!(
+                "closed as stale: no update since {} (older than {}s); was {}",
+                entry.updated_at,
+                params.older_than_secs,
+                wire_name(&entry.status)
+            )),
+            tags: Vec::new(),
+            severity: None,
+        });
+        entry.status = ReviewerStatus::Error;
+        entry.finished_at = Some(timestamp.clone());
+        entry.updated_at.clone_from(&timestamp);
+    }
+
+    if guard.is_some() && !closed.is_empty() {
+        write_session_file_atomic(&params.session, &params.lock_owner, &session)?;
+    }
+    Ok(CloseStaleResult {
+        dry_run: params.dry_run,
+        closed,
+    })
+}
+
+fn new_session_file(repo_root: &Path, session_date: Date) -> anyhow::Result<SessionFile> {
+    let repo_root = repo_root
+        .canonicalize()
+        .with_context(|| format!("canonicalize repo_root {}", repo_root.display()))?;
+    Ok(SessionFile {
+        schema_version: CURRENT_SCHEMA_VERSION.to_string(),
+        session_date: session_date.to_string(),
+        repo_root: repo_root.to_string_lossy().to_string(),
+        reviewers: vec![],
+        reviews: vec![],
+    })
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`init_session`].
+pub struct InitSessionParams {
+    /// Repo root stored in the new session file (as a canonical path).
+    pub repo_root: PathBuf,
+    /// Session date stored in the new session file.
+    pub session_date: Date,
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Lock owner id8 used while creating `_session.json`.
+    pub lock_owner: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result returned by [`init_session`].
+pub struct InitSessionResult {
+    /// Session directory path.
+    pub session_dir: String,
+    /// Session file path (`_session.json`).
+    pub session_file: String,
+    /// Whether the session file was written (false when it already existed).
+    pub created: bool,
+}
+
+/// Create the session directory and an empty `_session.json` so watchers can attach before any
+/// reviewer registers.
+///
+/// An existing session file is left untouched (it is not read or validated).
+///
+/// # Errors
+/// Returns an error if the lock owner is invalid, the repo root cannot be canonicalized, the
+/// directory or file cannot be written, or the lock cannot be acquired.
+pub fn init_session(params: &InitSessionParams) -> anyhow::Result<InitSessionResult> {
+    validate_id8(&params.lock_owner, "lock_owner")?;
+    let session_dir = params.session.session_dir();
+    fs::create_dir_all(session_dir)
+        .with_context(|| format!("create session dir {}", session_dir.display()))?;
+    let _guard = lock::acquire_lock(
+        session_dir,
+        params.lock_owner.clone(),
+        params.session.lock_config(),
+    )?;
+
+    let created = !params.session.session_file().exists();
+    if created {
+        let session = new_session_file(&params.repo_root, params.session_date)?;
+        write_session_file_atomic(&params.session, &params.lock_owner, &session)?;
+    }
+    Ok(InitSessionResult {
+        session_dir: session_dir.to_string_lossy().to_string(),
+        session_file: params.session.session_file().to_string_lossy().to_string(),
+        created,
+    })
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`compact_session`].
+pub struct CompactSessionParams {
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Lock owner id8 used while updating `_session.json`.
+    pub lock_owner: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result returned by [`compact_session`].
+pub struct CompactSessionResult {
+    /// Whether the file was rewritten (false when it was already canonical).
+    pub changed: bool,
+}
 
-    let lock_owner = params.reviewer_id.clone();
+/// Rewrite `_session.json` in the canonical layout every mutation writes.
+///
+/// The file is parsed strictly and reserialized from the typed structs, so key order and
+/// whitespace become deterministic while the content is unchanged. An already-canonical file is
+/// left untouched.
+///
+/// # Errors
+/// Returns an error if the lock owner is invalid, the session cannot be read, parsed, or written,
+/// or the lock cannot be acquired.
+pub fn compact_session(params: &CompactSessionParams) -> anyhow::Result<CompactSessionResult> {
+    validate_id8(&params.lock_owner, "lock_owner")?;
     let _guard = lock::acquire_lock(
         params.session.session_dir(),
-        lock_owner,
-        LockConfig::default(),
+        params.lock_owner.clone(),
+        params.session.lock_config(),
     )?;
+    let path = params.session.session_file();
+    let raw = fs::read_to_string(&path)
+        .with_context(|| format!("read session file {}", path.display()))?;
+    let session: SessionFile =
+        serde_json::from_str(&raw).with_context(|| format!("parse JSON {}", path.display()))?;
+    let changed = session_file_body(&session)? != raw;
+    if changed {
+        write_session_file_atomic(&params.session, &params.lock_owner, &session)?;
+    }
+    Ok(CompactSessionResult { changed })
+}
 
-    let mut session = read_session_file(params.session.session_dir())?;
+#[derive(Debug, Clone, Serialize)]
+/// A file in the session directory that no review entry references.
+pub struct OrphanReport {
+    /// File name within the session directory.
+    pub file: String,
+    /// Full path to the file.
+    pub path: String,
+    /// File size in bytes.
+    pub bytes: u64,
+}
 
-    let entry = session
-        .reviews
-        .iter_mut()
-        .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
-        .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
+#[derive(Debug, Clone, Serialize)]
+/// Result returned by [`find_orphan_reports`].
+pub struct OrphanReportsResult {
+    /// Session directory that was scanned.
+    pub session_dir: String,
+    /// Unreferenced files, sorted by name.
+    pub orphans: Vec<OrphanReport>,
+    /// Sum of [`OrphanReport::bytes`].
+    pub total_bytes: u64,
+}
 
-    if let Some(status) = params.status {
-        entry.status = status;
-    }
-    if let Some(phase) = params.phase {
-        entry.current_phase = phase;
+/// List report files in the session directory that no review entry's `report_file` points at.
+///
+/// Read-only: nothing is locked or deleted. Session bookkeeping files (`_session.json` and its
+/// lock/temp siblings, [`NOTES_ARCHIVE_FILE`]) and subdirectories are never listed.
+///
+/// # Errors
+/// Returns an error if the session cannot be read or the directory cannot be listed.
+pub fn find_orphan_reports(session: &SessionLocator) -> anyhow::Result<OrphanReportsResult> {
+    let session_dir = session.session_dir();
+    let data = read_session_file(session_dir)?;
+    let repo_root = Path::new(&data.repo_root);
+    let referenced: BTreeSet<PathBuf> = data
+        .reviews
+        .iter()
+        .filter_map(|entry| entry.report_file.as_deref())
+        .filter_map(|file| {
+            resolve_report_file_path(repo_root, session_dir, file)
+                .canonicalize()
+                .ok()
+        })
+        .collect();
+
+    let mut orphans = Vec::new();
+    let dir = fs::read_dir(session_dir)
+        .with_context(|| format!("list session dir {}", session_dir.display()))?;
+    for dirent in dir {
+        let dirent =
+            dirent.with_context(|| format!("list session dir {}", session_dir.display()))?;
+        let file = dirent.file_name().to_string_lossy().to_string();
+        if file.starts_with("_session.json") || file == NOTES_ARCHIVE_FILE {
+            continue;
+        }
+        let path = dirent.path();
+        let meta = fs::metadata(&path).with_context(|| format!("stat {}", path.display()))?;
+        if !meta.is_file() {
+            continue;
+        }
+        let canonical = path
+            .canonicalize()
+            .with_context(|| format!("canonicalize {}", path.display()))?;
+        if referenced.contains(&canonical) {
+            continue;
+        }
+        orphans.push(OrphanReport {
+            file,
+            path: path.to_string_lossy().to_string(),
+            bytes: meta.len(),
+        });
     }
-    entry.updated_at = format_ts(params.now)?;
+    orphans.sort_by(|a, b| a.file.cmp(&b.file));
+    let total_bytes = orphans.iter().map(|orphan| orphan.bytes).sum();
+    Ok(OrphanReportsResult {
+        session_dir: session_dir.to_string_lossy().to_string(),
+        orphans,
+        total_bytes,
+    })
+}
+
+/// Default report file extension used by [`finalize_review`].
+pub const DEFAULT_REPORT_EXT: &str = "md";
 
-    write_session_file_atomic(params.session.session_dir(), &params.reviewer_id, &session)?;
+fn validate_report_ext(ext: &str) -> anyhow::Result<()> {
+    if ext.is_empty() || !ext.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(anyhow::anyhow!(
+            "invalid report extension {ext:?} (expected ASCII alphanumeric, without a leading dot)"
+        ));
+    }
     Ok(())
 }
 
+/// Placeholder for the `started_at` time prefix in [`ReportHint::report_file_pattern`].
+pub const REPORT_TIME_PLACEHOLDER: &str = "{HH-MM-SS-mmm}";
+
+/// Report filenames are `{HH-MM-SS-mmm}_{sanitized_target_ref}_{reviewer_id}.{ext}`, where the
+/// time prefix is the entry's `started_at` (UTC).
 fn report_file_name(
     started_at: OffsetDateTime,
     target_ref: &str,
     reviewer_id: &str,
+    ext: &str,
 ) -> anyhow::Result<String> {
     let fmt = time::format_description::parse("[hour]-[minute]-[second]-[subsecond digits:3]")
         .context("parse time format")?;
     let prefix = started_at
         .format(&fmt)
         .context("format report time prefix")?;
+    Ok(format_report_file_name(
+        &prefix,
+        target_ref,
+        reviewer_id,
+        ext,
+    ))
+}
+
+fn format_report_file_name(prefix: &str, target_ref: &str, reviewer_id: &str, ext: &str) -> String {
     let sanitized = paths::sanitize_ref(target_ref);
-    Ok(format!("{prefix}_{sanitized}_{reviewer_id}.md"))
+    format!("{prefix}_{sanitized}_{reviewer_id}.{ext}")
 }
 
 #[derive(Debug, Clone)]
@@ -1501,19 +3718,86 @@ pub struct FinalizeReviewParams {
     pub verdict: ReviewVerdict,
     /// Severity counts to record.
     pub counts: SeverityCounts,
-    /// Report markdown contents to write to disk.
-    pub report_markdown: String,
+    /// Report markdown contents to write to disk (`None` finalizes verdict/counts without a report).
+    pub report_markdown: Option<String>,
     /// Timestamp written to `finished_at` and `updated_at`.
     pub now: OffsetDateTime,
+    /// If the entry already has a report, accept identical markdown as a no-op (updating only
+    /// verdict/counts) instead of refusing. Without a report, allow re-recording verdict/counts
+    /// on an entry that is already terminal.
+    pub update_if_finished: bool,
+    /// Report file extension without the leading dot (see [`DEFAULT_REPORT_EXT`]).
+    pub report_ext: String,
+    /// Accept empty or whitespace-only report markdown (rejected by default).
+    pub allow_empty_report: bool,
+    /// Also set the entry's `initiator_status` in the same write that marks it `FINISHED`
+    /// (`None` leaves it unchanged).
+    pub initiator_status: Option<InitiatorStatus>,
+    /// Template text the report is wrapped in (see [`REPORT_TEMPLATE_PLACEHOLDERS`]); requires a
+    /// report.
+    pub template: Option<String>,
+    /// If pointing the session entry at the new report fails, delete the report file so a retry
+    /// does not collide with it.
+    pub cleanup_on_failure: bool,
+}
+
+/// Placeholders accepted in a finalize template, written as `{{name}}`; `body` is the report.
+pub const REPORT_TEMPLATE_PLACEHOLDERS: [&str; 11] = [
+    "reviewer_id",
+    "session_id",
+    "target_ref",
+    "verdict",
+    "date",
+    "finished_at",
+    "counts.blocker",
+    "counts.major",
+    "counts.minor",
+    "counts.nit",
+    "body",
+];
+
+/// Substitute `{{name}}` placeholders (surrounding whitespace inside the braces is ignored).
+/// `values` pairs each of [`REPORT_TEMPLATE_PLACEHOLDERS`] with its text.
+fn render_report_template(template: &str, values: &[(&str, String)]) -> anyhow::Result<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some((before, after)) = rest.split_once("{{") {
+        out.push_str(before);
+        let (name, tail) = after
+            .split_once("}}")
+            .ok_or_else(|| anyhow::anyhow!("unclosed `{{{{` in report template"))?;
+        let name = name.trim();
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "unknown report template placeholder `{{{{{name}}}}}` (expected one of: {})",
+                    REPORT_TEMPLATE_PLACEHOLDERS.join(", ")
+                )
+            })?;
+        out.push_str(value);
+        rest = tail;
+    }
+    out.push_str(rest);
+    Ok(out)
 }
 
 #[derive(Debug, Clone, Serialize)]
 /// Result returned by [`finalize_review`].
 pub struct FinalizeReviewResult {
-    /// Report path relative to the repo root.
-    pub report_file: String,
-    /// Full report path as a string.
-    pub report_path: String,
+    /// Report path relative to the repo root (`None` when finalized without a report).
+    pub report_file: Option<String>,
+    /// Full report path as a string (`None` when finalized without a report).
+    pub report_path: Option<String>,
+}
+
+fn normalize_report_markdown(mut report: String) -> String {
+    if !report.ends_with('\n') {
+        report.push('\n');
+    }
+    report
 }
 
 /// Finalize a review entry: write the report file and update `_session.json`.
@@ -1523,48 +3807,162 @@ pub struct FinalizeReviewResult {
 /// 2) write report markdown file (outside the session lock)
 /// 3) lock + update the session entry to `FINISHED` and point at the report file
 ///
+/// With [`FinalizeReviewParams::update_if_finished`], an entry that already has a report is
+/// accepted when the existing report is identical to the supplied markdown: only the
+/// verdict/counts are updated (if they differ) and the existing report paths are returned.
+///
+/// Empty or whitespace-only markdown is rejected up front unless
+/// [`FinalizeReviewParams::allow_empty_report`] is set.
+///
+/// When [`FinalizeReviewParams::report_markdown`] is `None`, step 1 marks the entry `FINISHED`
+/// with verdict/counts only, writes no file, and leaves `report_file` null. An entry that is
+/// already terminal is refused unless [`FinalizeReviewParams::update_if_finished`] is set, in
+/// which case only verdict/counts (and `updated_at`) change.
+///
+/// [`FinalizeReviewParams::initiator_status`], when set, is applied in whichever write marks the
+/// entry `FINISHED`, so single-agent flows need no separate `set-status` call.
+///
+/// With [`FinalizeReviewParams::template`], the markdown is substituted at `{{body}}` and the
+/// other placeholders are filled from the entry, verdict, counts, and `now` before anything is
+/// written; unknown placeholders are an error.
+///
+/// If step 3 fails, the report written in step 2 is left behind unless
+/// [`FinalizeReviewParams::cleanup_on_failure`] is set, in which case it is removed.
+///
 /// # Errors
 /// Returns an error if identifiers are invalid, report files cannot be written,
 /// or the session cannot be read or written.
+#[allow(clippy::too_many_lines)]
 pub fn finalize_review(params: FinalizeReviewParams) -> anyhow::Result<FinalizeReviewResult> {
     validate_id8(&params.reviewer_id, "reviewer_id")?;
     validate_id8(&params.session_id, "session_id")?;
+    validate_report_ext(&params.report_ext)?;
+    if !params.allow_empty_report
+        && params
+            .report_markdown
+            .as_deref()
+            .is_some_and(|markdown| markdown.trim().is_empty())
+    {
+        return Err(anyhow::anyhow!(
+            "report markdown is empty; pass --allow-empty-report to write it anyway"
+        ));
+    }
+    if params.template.is_some() && params.report_markdown.is_none() {
+        return Err(anyhow::anyhow!(
+            "a report template requires report markdown"
+        ));
+    }
 
     // Step 1: read the session file (locked) and compute the report filename.
     let started_at;
     let target_ref;
     let repo_root;
+    let report_markdown;
     {
         let lock_owner = params.reviewer_id.clone();
         let _guard = lock::acquire_lock(
             params.session.session_dir(),
             lock_owner,
-            LockConfig::default(),
+            params.session.lock_config(),
         )?;
-        let session = read_session_file(params.session.session_dir())?;
+        let mut session = read_session_file(params.session.session_dir())?;
         repo_root = PathBuf::from(&session.repo_root);
         let entry = session
             .reviews
-            .iter()
+            .iter_mut()
             .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
             .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
-        if entry.report_file.is_some() {
-            return Err(anyhow::anyhow!(
-                "report_file already set; refusing to overwrite"
-            ));
+        let Some(markdown) = params.report_markdown else {
+            // Verdict-only closure: nothing to write outside the lock.
+            if entry.report_file.is_some() {
+                return Err(anyhow::anyhow!(
+                    "report_file already set; refusing to finalize without a report"
+                ));
+            }
+            if entry.status.is_terminal() {
+                if !params.update_if_finished {
+                    return Err(anyhow::anyhow!(
+                        "review is already {}; refusing to finalize again (pass --update-if-finished to replace verdict/counts)",
+                        wire_name(&entry.status)
+                    ));
+                }
+                entry.verdict = Some(params.verdict);
+                entry.counts = params.counts;
+                entry.updated_at = format_ts(params.now)?;
+            } else {
+                mark_finished(entry, params.verdict, params.counts, None, params.now)?;
+            }
+            if let Some(initiator_status) = params.initiator_status {
+                entry.initiator_status = initiator_status;
+            }
+            write_session_file_atomic(&params.session, &params.reviewer_id, &session)?;
+            return Ok(FinalizeReviewResult {
+                report_file: None,
+                report_path: None,
+            });
+        };
+        let markdown = match params.template.as_deref() {
+            Some(template) => render_report_template(
+                template,
+                &[
+                    ("reviewer_id", entry.reviewer_id.clone()),
+                    ("session_id", entry.session_id.clone()),
+                    ("target_ref", entry.target_ref.clone()),
+                    ("verdict", wire_name(&params.verdict)),
+                    ("date", params.now.date().to_string()),
+                    ("finished_at", format_ts(params.now)?),
+                    ("counts.blocker", params.counts.blocker.to_string()),
+                    ("counts.major", params.counts.major.to_string()),
+                    ("counts.minor", params.counts.minor.to_string()),
+                    ("counts.nit", params.counts.nit.to_string()),
+                    ("body", markdown),
+                ],
+            )?,
+            None => markdown,
+        };
+        if let Some(existing_file) = entry.report_file.clone() {
+            if !params.update_if_finished {
+                return Err(anyhow::anyhow!(
+                    "report_file already set; refusing to overwrite"
+                ));
+            }
+            let existing_path =
+                resolve_report_file_path(&repo_root, params.session.session_dir(), &existing_file);
+            let existing = fs::read_to_string(&existing_path).with_context(|| {
+                format!("read existing report file {}", existing_path.display())
+            })?;
+            let report = normalize_report_markdown(markdown);
+            if existing != report {
+                return Err(anyhow::anyhow!(
+                    "report_file already set with different contents; refusing to overwrite"
+                ));
+            }
+            if entry.verdict != Some(params.verdict) || entry.counts != params.counts {
+                entry.verdict = Some(params.verdict);
+                entry.counts = params.counts;
+                entry.updated_at = format_ts(params.now)?;
+                write_session_file_atomic(&params.session, &params.reviewer_id, &session)?;
+            }
+            return Ok(FinalizeReviewResult {
+                report_file: Some(existing_file),
+                report_path: Some(existing_path.to_string_lossy().to_string()),
+            });
         }
         started_at = parse_ts(&entry.started_at)?;
         target_ref = entry.target_ref.clone();
+        report_markdown = markdown;
     }
 
-    let filename = report_file_name(started_at, &target_ref, &params.reviewer_id)?;
+    let filename = report_file_name(
+        started_at,
+        &target_ref,
+        &params.reviewer_id,
+        &params.report_ext,
+    )?;
     let report_path = params.session.session_dir().join(&filename);
 
     // Step 2: write report file (outside the session lock).
-    let mut report = params.report_markdown;
-    if !report.ends_with('\n') {
-        report.push('\n');
-    }
+    let report = normalize_report_markdown(report_markdown);
     let mut f = std::fs::OpenOptions::new()
         .write(true)
         .create_new(true)
@@ -1579,12 +3977,12 @@ pub fn finalize_review(params: FinalizeReviewParams) -> anyhow::Result<FinalizeR
         .map_or(filename, |rel| rel.to_string_lossy().to_string());
 
     // Step 3: update session JSON (locked) to point at the report.
-    {
+    let updated = (|| -> anyhow::Result<()> {
         let lock_owner = params.reviewer_id.clone();
         let _guard = lock::acquire_lock(
             params.session.session_dir(),
             lock_owner,
-            LockConfig::default(),
+            params.session.lock_config(),
         )?;
         let mut session = read_session_file(params.session.session_dir())?;
         let entry = session
@@ -1593,20 +3991,127 @@ pub fn finalize_review(params: FinalizeReviewParams) -> anyhow::Result<FinalizeR
             .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
             .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
 
-        entry.status = ReviewerStatus::Finished;
-        entry.current_phase = Some(ReviewPhase::ReportWriting);
-        entry.verdict = Some(params.verdict);
-        entry.counts = params.counts;
-        entry.report_file = Some(report_file.clone());
-        entry.finished_at = Some(format_ts(params.now)?);
-        entry.updated_at = format_ts(params.now)?;
+        mark_finished(
+            entry,
+            params.verdict,
+            params.counts,
+            Some(report_file.clone()),
+            params.now,
+        )?;
+        if let Some(initiator_status) = params.initiator_status {
+            entry.initiator_status = initiator_status;
+        }
 
-        write_session_file_atomic(params.session.session_dir(), &params.reviewer_id, &session)?;
+        write_session_file_atomic(&params.session, &params.reviewer_id, &session)
+    })();
+    if let Err(err) = updated {
+        if params.cleanup_on_failure {
+            if let Err(remove_err) = fs::remove_file(&report_path) {
+                return Err(err.context(format!(
+                    "also failed to remove orphan report file {}: {remove_err}",
+                    report_path.display()
+                )));
+            }
+        }
+        return Err(err);
     }
 
     Ok(FinalizeReviewResult {
-        report_file,
-        report_path: report_path.to_string_lossy().to_string(),
+        report_file: Some(report_file),
+        report_path: Some(report_path.to_string_lossy().to_string()),
+    })
+}
+
+fn mark_finished(
+    entry: &mut ReviewEntry,
+    verdict: ReviewVerdict,
+    counts: SeverityCounts,
+    report_file: Option<String>,
+    now: OffsetDateTime,
+) -> anyhow::Result<()> {
+    entry.status = ReviewerStatus::Finished;
+    entry.current_phase = Some(ReviewPhase::ReportWriting);
+    entry.verdict = Some(verdict);
+    entry.counts = counts;
+    entry.report_file = report_file;
+    entry.finished_at = Some(format_ts(now)?);
+    entry.updated_at = format_ts(now)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`amend_counts`].
+pub struct AmendCountsParams {
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Reviewer id for the entry being corrected (id8).
+    pub reviewer_id: String,
+    /// Session id for the entry being corrected (id8).
+    pub session_id: String,
+    /// Corrected severity counts.
+    pub counts: SeverityCounts,
+    /// Timestamp written to `updated_at` and the correction note.
+    pub now: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result returned by [`amend_counts`].
+pub struct AmendCountsResult {
+    /// Counts recorded before the amendment.
+    pub previous: SeverityCounts,
+    /// Counts recorded after the amendment.
+    pub counts: SeverityCounts,
+}
+
+/// Correct the severity counts on a `FINISHED` review entry.
+///
+/// Only `counts` and `updated_at` change; the verdict, report file, and `finished_at` are left
+/// as finalized. An `acknowledged` reviewer note recording the previous and corrected counts is
+/// appended in the same atomic write.
+///
+/// # Errors
+/// Returns an error if identifiers are invalid, the entry is missing or not `FINISHED`, or the
+/// session cannot be read or written.
+pub fn amend_counts(params: &AmendCountsParams) -> anyhow::Result<AmendCountsResult> {
+    validate_id8(&params.reviewer_id, "reviewer_id")?;
+    validate_id8(&params.session_id, "session_id")?;
+
+    let _guard = lock::acquire_lock(
+        params.session.session_dir(),
+        params.reviewer_id.clone(),
+        params.session.lock_config(),
+    )?;
+    let mut session = read_session_file(params.session.session_dir())?;
+    let entry = session
+        .reviews
+        .iter_mut()
+        .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
+        .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
+    if entry.status != ReviewerStatus::Finished {
+        return Err(anyhow::anyhow!(
+            "review entry is not FINISHED ({}); only finalized counts can be amended",
+            wire_name(&entry.status)
+        ));
+    }
+
+    let timestamp = format_ts(params.now)?;
+    let previous = std::mem::replace(&mut entry.counts, params.counts.clone());
+    entry.notes.push(SessionNote {
+        role: NoteRole::Reviewer,
+        timestamp: timestamp.clone(),
+        note_type: NoteType::Acknowledged,
+        content: serde_json::json!({
+            "amended_counts": { "previous": previous, "counts": params.counts },
+        }),
+        tags: Vec::new(),
+        severity: None,
+    });
+    entry.updated_at = timestamp;
+
+    write_session_file_atomic(&params.session, &params.reviewer_id, &session)?;
+    Ok(AmendCountsResult {
+        previous,
+        counts: params.counts.clone(),
     })
 }
 
@@ -1629,23 +4134,70 @@ pub struct AppendNoteParams {
     pub now: OffsetDateTime,
     /// Lock owner id8 used while updating `_session.json`.
     pub lock_owner: String,
+    /// Skip the append (still succeeding) when the entry's most recent note has the same
+    /// role, note type, and content.
+    pub dedupe: bool,
+    /// After appending, drop the oldest notes so at most this many remain (must be >= 1).
+    pub max_notes: Option<usize>,
+    /// Append notes dropped by `max_notes` to [`NOTES_ARCHIVE_FILE`] in the session directory.
+    pub archive_pruned: bool,
+    /// Tags stored on the new note (each validated by [`validate_note_tag`]).
+    pub tags: Vec<String>,
+    /// Optional severity stored on the new note.
+    pub severity: Option<Severity>,
+    /// Also set `initiator_status` in the same write (applied even when `dedupe` skips the note).
+    pub initiator_status: Option<InitiatorStatus>,
+}
+
+/// JSONL file (one [`FlattenedNote`] per line) receiving notes pruned by `--max-notes`.
+pub const NOTES_ARCHIVE_FILE: &str = "notes_archive.jsonl";
+
+fn archive_notes(session_dir: &Path, notes: &[FlattenedNote]) -> anyhow::Result<()> {
+    let path = session_dir.join(NOTES_ARCHIVE_FILE);
+    let mut f = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("open notes archive {}", path.display()))?;
+    let mut buf = Vec::new();
+    for note in notes {
+        serde_json::to_writer(&mut buf, note).context("serialize archived note")?;
+        buf.push(b'\n');
+    }
+    f.write_all(&buf)
+        .with_context(|| format!("write notes archive {}", path.display()))?;
+    f.flush()
+        .with_context(|| format!("flush notes archive {}", path.display()))
 }
 
 /// Append a note to the `notes` array for a review entry.
 ///
+/// With [`AppendNoteParams::dedupe`], only the most recent note is compared (not the whole
+/// array), which is enough to absorb idempotent retries.
+///
+/// With [`AppendNoteParams::max_notes`], the oldest notes beyond the limit are pruned after the
+/// append (the new note is always kept), optionally spilling them to [`NOTES_ARCHIVE_FILE`]
+/// once `_session.json` has been rewritten (a failed write archives nothing).
+///
 /// # Errors
 /// Returns an error if identifiers are invalid, the session cannot be read or written,
-/// or the lock cannot be acquired.
+/// the lock cannot be acquired, or the archive append fails (after the session was written).
 pub fn append_note(params: AppendNoteParams) -> anyhow::Result<()> {
     validate_id8(&params.reviewer_id, "reviewer_id")?;
     validate_id8(&params.session_id, "session_id")?;
     validate_id8(&params.lock_owner, "lock_owner")?;
+    if params.max_notes == Some(0) {
+        return Err(anyhow::anyhow!("max_notes must be at least 1"));
+    }
+    for tag in &params.tags {
+        validate_note_tag(tag)?;
+    }
 
     let lock_owner = params.lock_owner.clone();
     let _guard = lock::acquire_lock(
         params.session.session_dir(),
         lock_owner.clone(),
-        LockConfig::default(),
+        params.session.lock_config(),
     )?;
     let mut session = read_session_file(params.session.session_dir())?;
     let entry = session
@@ -1654,15 +4206,55 @@ pub fn append_note(params: AppendNoteParams) -> anyhow::Result<()> {
         .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
         .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
 
-    entry.notes.push(SessionNote {
-        role: params.role,
-        timestamp: format_ts(params.now)?,
-        note_type: params.note_type,
-        content: params.content,
-    });
+    let duplicate = params.dedupe
+        && entry.notes.last().is_some_and(|last| {
+            last.role == params.role
+                && last.note_type == params.note_type
+                && last.content == params.content
+        });
+    if duplicate && params.initiator_status.is_none() {
+        return Ok(());
+    }
+
+    if let Some(initiator_status) = params.initiator_status {
+        entry.initiator_status = initiator_status;
+    }
+    if !duplicate {
+        entry.notes.push(SessionNote {
+            role: params.role,
+            timestamp: format_ts(params.now)?,
+            note_type: params.note_type,
+            content: params.content,
+            tags: params.tags,
+            severity: params.severity,
+        });
+    }
     entry.updated_at = format_ts(params.now)?;
 
-    write_session_file_atomic(params.session.session_dir(), &lock_owner, &session)?;
+    let mut archived: Vec<FlattenedNote> = Vec::new();
+    if let Some(max_notes) = params.max_notes {
+        let excess = entry.notes.len().saturating_sub(max_notes);
+        if excess > 0 {
+            let pruned = entry.notes.drain(..excess);
+            if params.archive_pruned {
+                archived = pruned
+                    .map(|note| FlattenedNote {
+                        reviewer_id: entry.reviewer_id.clone(),
+                        session_id: entry.session_id.clone(),
+                        target_ref: entry.target_ref.clone(),
+                        note,
+                    })
+                    .collect();
+            }
+        }
+    }
+
+    write_session_file_atomic(&params.session, &lock_owner, &session)?;
+    // Only archive once the prune is committed, so a failed write cannot duplicate notes that
+    // are still in `_session.json`.
+    if !archived.is_empty() {
+        archive_notes(params.session.session_dir(), &archived)?;
+    }
     Ok(())
 }
 
@@ -1697,7 +4289,7 @@ pub fn set_initiator_status(params: &SetInitiatorStatusParams) -> anyhow::Result
     let _guard = lock::acquire_lock(
         params.session.session_dir(),
         lock_owner.clone(),
-        LockConfig::default(),
+        params.session.lock_config(),
     )?;
     let mut session = read_session_file(params.session.session_dir())?;
     let entry = session
@@ -1709,6 +4301,294 @@ pub fn set_initiator_status(params: &SetInitiatorStatusParams) -> anyhow::Result
     entry.initiator_status = params.initiator_status;
     entry.updated_at = format_ts(params.now)?;
 
-    write_session_file_atomic(params.session.session_dir(), &lock_owner, &session)?;
+    write_session_file_atomic(&params.session, &lock_owner, &session)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`decline_all`].
+pub struct DeclineAllParams {
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Reviewer id for the entry being declined (id8).
+    pub reviewer_id: String,
+    /// Session id for the entry being declined (id8).
+    pub session_id: String,
+    /// Reason recorded as the `declined` note content.
+    pub reason: String,
+    /// Timestamp written to `updated_at` and the note.
+    pub now: OffsetDateTime,
+    /// Lock owner id8 used while updating `_session.json`.
+    pub lock_owner: String,
+}
+
+/// Decline every finding of a terminal review in one step.
+///
+/// Sets `initiator_status` to `APPLIED` (the applicator is done with the review) and appends a
+/// single applicator `declined` note carrying the reason, in one atomic write.
+///
+/// # Errors
+/// Returns an error if identifiers are invalid, the reason is empty, the entry is missing or not
+/// yet terminal, the session cannot be read or written, or the lock cannot be acquired.
+pub fn decline_all(params: &DeclineAllParams) -> anyhow::Result<()> {
+    validate_id8(&params.reviewer_id, "reviewer_id")?;
+    validate_id8(&params.session_id, "session_id")?;
+    validate_id8(&params.lock_owner, "lock_owner")?;
+    if params.reason.trim().is_empty() {
+        return Err(anyhow::anyhow!("--reason must not be empty"));
+    }
+
+    let _guard = lock::acquire_lock(
+        params.session.session_dir(),
+        params.lock_owner.clone(),
+        params.session.lock_config(),
+    )?;
+    let mut session = read_session_file(params.session.session_dir())?;
+    let entry = session
+        .reviews
+        .iter_mut()
+        .find(|r| r.reviewer_id == params.reviewer_id && r.session_id == params.session_id)
+        .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
+    if !entry.status.is_terminal() {
+        return Err(anyhow::anyhow!(
+            "review entry is not terminal ({}); nothing to decline yet",
+            wire_name(&entry.status)
+        ));
+    }
+
+    let timestamp = format_ts(params.now)?;
+    entry.initiator_status = InitiatorStatus::Applied;
+    entry.notes.push(SessionNote {
+        role: NoteRole::Applicator,
+        timestamp: timestamp.clone(),
+        note_type: NoteType::Declined,
+        content: Value::String(params.reason.clone()),
+        tags: Vec::new(),
+        severity: None,
+    });
+    entry.updated_at = timestamp;
+
+    write_session_file_atomic(&params.session, &params.lock_owner, &session)?;
     Ok(())
 }
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "kebab-case", deny_unknown_fields)]
+/// One mutation in an [`apply_batch`] file; `op` selects the operation.
+pub enum BatchOp {
+    /// Set the applicator-owned `initiator_status` (as `applicator set-status`).
+    SetStatus {
+        /// Reviewer id for the entry being updated (id8).
+        reviewer_id: String,
+        /// Session id for the entry being updated (id8).
+        session_id: String,
+        /// New applicator-owned status.
+        initiator_status: InitiatorStatus,
+    },
+    /// Append a note (as `reviewer note` / `applicator note`).
+    Note {
+        /// Reviewer id for the entry being updated (id8).
+        reviewer_id: String,
+        /// Session id for the entry being updated (id8).
+        session_id: String,
+        /// Author role of the note.
+        role: NoteRole,
+        #[serde(rename = "type")]
+        /// Structured note type.
+        note_type: NoteType,
+        /// Note content (any JSON value).
+        content: Value,
+        /// Optional tags (each validated by [`validate_note_tag`]).
+        #[serde(default)]
+        tags: Vec<String>,
+        /// Optional note severity.
+        #[serde(default)]
+        severity: Option<Severity>,
+    },
+    /// Update the reviewer-owned `status` and/or `current_phase` (as `reviewer update`).
+    Update {
+        /// Reviewer id for the entry being updated (id8).
+        reviewer_id: String,
+        /// Session id for the entry being updated (id8).
+        session_id: String,
+        /// New reviewer-owned status (unchanged if absent).
+        #[serde(default)]
+        status: Option<ReviewerStatus>,
+        /// New current phase (unchanged if absent).
+        #[serde(default)]
+        phase: Option<ReviewPhase>,
+        /// Clear `current_phase` (conflicts with `phase`).
+        #[serde(default)]
+        clear_phase: bool,
+    },
+}
+
+impl BatchOp {
+    fn ids(&self) -> (&str, &str) {
+        match self {
+            Self::SetStatus {
+                reviewer_id,
+                session_id,
+                ..
+            }
+            | Self::Note {
+                reviewer_id,
+                session_id,
+                ..
+            }
+            | Self::Update {
+                reviewer_id,
+                session_id,
+                ..
+            } => (reviewer_id, session_id),
+        }
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        let (reviewer_id, session_id) = self.ids();
+        validate_id8(reviewer_id, "reviewer_id")?;
+        validate_id8(session_id, "session_id")?;
+        match self {
+            Self::SetStatus { .. } => {}
+            Self::Note { tags, .. } => {
+                for tag in tags {
+                    validate_note_tag(tag)?;
+                }
+            }
+            Self::Update {
+                status,
+                phase,
+                clear_phase,
+                ..
+            } => {
+                if *clear_phase && phase.is_some() {
+                    return Err(anyhow::anyhow!(
+                        "phase and clear_phase are mutually exclusive"
+                    ));
+                }
+                if status.is_none() && phase.is_none() && !*clear_phase {
+                    return Err(anyhow::anyhow!(
+                        "update needs at least one of status, phase, clear_phase"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(self, session: &mut SessionFile, timestamp: &str) -> anyhow::Result<()> {
+        let (reviewer_id, session_id) = self.ids();
+        let entry = session
+            .reviews
+            .iter_mut()
+            .find(|r| r.reviewer_id == reviewer_id && r.session_id == session_id)
+            .ok_or_else(|| anyhow::anyhow!("review entry not found for reviewer_id/session_id"))?;
+        match self {
+            Self::SetStatus {
+                initiator_status, ..
+            } => entry.initiator_status = initiator_status,
+            Self::Note {
+                role,
+                note_type,
+                content,
+                tags,
+                severity,
+                ..
+            } => entry.notes.push(SessionNote {
+                role,
+                timestamp: timestamp.to_string(),
+                note_type,
+                content,
+                tags,
+                severity,
+            }),
+            Self::Update {
+                status,
+                phase,
+                clear_phase,
+                ..
+            } => {
+                if let Some(status) = status {
+                    entry.status = status;
+                }
+                if clear_phase {
+                    entry.current_phase = None;
+                } else if phase.is_some() {
+                    entry.current_phase = phase;
+                }
+            }
+        }
+        entry.updated_at = timestamp.to_string();
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Parameters for [`apply_batch`].
+pub struct BatchParams {
+    /// Session directory locator.
+    pub session: SessionLocator,
+    /// Operations applied in order.
+    pub ops: Vec<BatchOp>,
+    /// Timestamp written to `updated_at` and any new notes.
+    pub now: OffsetDateTime,
+    /// Lock owner id8 used while updating `_session.json`.
+    pub lock_owner: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result of [`apply_batch`].
+pub struct BatchResult {
+    /// Number of operations applied.
+    pub applied: usize,
+}
+
+/// Parse a batch file: one JSON [`BatchOp`] object per non-blank line.
+///
+/// # Errors
+/// Returns an error naming the first line that is not a valid operation.
+pub fn parse_batch_ops(input: &str) -> anyhow::Result<Vec<BatchOp>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line).with_context(|| format!("batch line {}", idx + 1))
+        })
+        .collect()
+}
+
+/// Apply `ops` to one `_session.json` under a single lock, writing once.
+///
+/// Every operation is validated and applied in memory first; if any fails, nothing is written.
+///
+/// # Errors
+/// Returns an error (naming the 1-based op index) if any op is invalid or targets a missing
+/// entry, or if the batch is empty, the session cannot be read or written, or the lock cannot be
+/// acquired.
+pub fn apply_batch(params: BatchParams) -> anyhow::Result<BatchResult> {
+    validate_id8(&params.lock_owner, "lock_owner")?;
+    if params.ops.is_empty() {
+        return Err(anyhow::anyhow!("batch contains no operations"));
+    }
+    for (idx, op) in params.ops.iter().enumerate() {
+        op.validate()
+            .with_context(|| format!("batch op {}", idx + 1))?;
+    }
+
+    let _guard = lock::acquire_lock(
+        params.session.session_dir(),
+        params.lock_owner.clone(),
+        params.session.lock_config(),
+    )?;
+    let mut session = read_session_file(params.session.session_dir())?;
+    let timestamp = format_ts(params.now)?;
+    let applied = params.ops.len();
+    for (idx, op) in params.ops.into_iter().enumerate() {
+        op.apply(&mut session, &timestamp)
+            .with_context(|| format!("batch op {}", idx + 1))?;
+    }
+
+    write_session_file_atomic(&params.session, &params.lock_owner, &session)?;
+    Ok(BatchResult { applied })
+}
@@ -0,0 +1,106 @@
+//! Opt-in phase timing for `_session.json` mutations.
+//!
+//! Inside [`record`], the lock and session read/write helpers record how long each phase took on
+//! the current thread; outside it they record nothing. `mutate` is the time between the end of
+//! the last session read and the start of the following write. The CLI wraps one command in
+//! [`record`] under `--trace` and prints the events as a `{"trace": [...]}` line on stderr.
+
+use serde::Serialize;
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+/// A timed phase of a session mutation.
+pub enum TracePhase {
+    /// Waiting for and creating the session lock.
+    LockAcquire,
+    /// Reading and parsing `_session.json`.
+    Read,
+    /// In-memory changes between a read and the following write.
+    Mutate,
+    /// Serializing and atomically replacing `_session.json`.
+    Write,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Elapsed time for one phase.
+pub struct TraceEvent {
+    /// Which phase was timed.
+    pub phase: TracePhase,
+    /// Elapsed wall-clock time in microseconds.
+    pub micros: u64,
+}
+
+#[derive(Default)]
+struct Recorder {
+    events: Vec<TraceEvent>,
+    last_read_end: Option<Instant>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Restores the recorder that was active before [`record`] (normally none), even if `f` panics.
+struct Scope {
+    previous: Option<Recorder>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        RECORDER.with(|r| *r.borrow_mut() = previous);
+    }
+}
+
+/// Run `f` with phase timing enabled on the current thread and return its events.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<TraceEvent>) {
+    let scope = Scope {
+        previous: RECORDER.with(|r| r.borrow_mut().replace(Recorder::default())),
+    };
+    let out = f();
+    let events = RECORDER.with(|r| {
+        r.borrow_mut()
+            .take()
+            .map_or_else(Vec::new, |recorder| recorder.events)
+    });
+    drop(scope);
+    (out, events)
+}
+
+fn with_recorder(f: impl FnOnce(&mut Recorder)) {
+    RECORDER.with(|r| {
+        if let Some(recorder) = r.borrow_mut().as_mut() {
+            f(recorder);
+        }
+    });
+}
+
+fn push(recorder: &mut Recorder, phase: TracePhase, elapsed: Duration) {
+    recorder.events.push(TraceEvent {
+        phase,
+        micros: u64::try_from(elapsed.as_micros()).map_or(u64::MAX, std::convert::identity),
+    });
+}
+
+/// Run `f`, recording its duration under `phase` when tracing is enabled.
+pub(crate) fn timed<T>(phase: TracePhase, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    if phase == TracePhase::Write {
+        with_recorder(|recorder| {
+            if let Some(read_end) = recorder.last_read_end.take() {
+                push(recorder, TracePhase::Mutate, start.duration_since(read_end));
+            }
+        });
+    }
+    let out = f();
+    let end = Instant::now();
+    with_recorder(|recorder| {
+        push(recorder, phase, end.duration_since(start));
+        if phase == TracePhase::Read {
+            recorder.last_read_end = Some(end);
+        }
+    });
+    out
+}
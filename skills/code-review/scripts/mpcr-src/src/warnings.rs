@@ -0,0 +1,74 @@
+//! Non-fatal warnings collected while running an operation.
+//!
+//! Operations that can partially succeed (e.g. a report file that cannot be read while listing
+//! reports) push a [`Warning`] into a [`Warnings`] accumulator instead of failing. Callers decide
+//! whether to surface them; the CLI emits them as a trailing `{"warnings": [...]}` line on stderr
+//! under `--warnings-json`.
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+/// Machine-readable warning category.
+pub enum WarningCode {
+    /// A report file could not be read while including report contents.
+    ReportRead,
+    /// A report file could not be stat'd while including report sizes.
+    ReportStat,
+    /// An entry timestamp could not be parsed while computing ages.
+    TimestampParse,
+    /// A requested temp-file directory was not usable for an atomic session write, so the temp
+    /// file was staged in the session directory instead.
+    TmpDirFallback,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// A single structured warning.
+pub struct Warning {
+    /// Warning category.
+    pub code: WarningCode,
+    /// Human-readable description (includes the underlying error).
+    pub message: String,
+    /// Reviewer id of the entry the warning relates to (if any).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewer_id: Option<String>,
+    /// Session id of the entry the warning relates to (if any).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Filesystem path the warning relates to (if any).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+/// Accumulator for [`Warning`]s, serialized as `{"warnings": [...]}`.
+pub struct Warnings {
+    warnings: Vec<Warning>,
+}
+
+impl Warnings {
+    /// Create an empty accumulator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Record a warning.
+    pub fn push(&mut self, warning: Warning) {
+        self.warnings.push(warning);
+    }
+
+    /// Whether no warnings have been recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Recorded warnings, in the order they were pushed.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Warning] {
+        &self.warnings
+    }
+}
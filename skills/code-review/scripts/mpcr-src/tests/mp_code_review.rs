@@ -1,14 +1,19 @@
 //! Integration tests for `mpcr` session coordination primitives.
 
 use anyhow::{bail, ensure};
+use clap::ValueEnum;
 use mpcr::lock::{self, LockConfig};
 use mpcr::session::{
-    collect_reports, finalize_review, register_reviewer, set_initiator_status,
-    FinalizeReviewParams, InitiatorStatus, NoteRole, NoteType, RegisterReviewerParams,
-    ReportsFilters, ReportsOptions, ReportsView, ReviewEntry, ReviewPhase, ReviewVerdict,
-    ReviewerStatus, SessionFile, SessionLocator, SessionNote, SetInitiatorStatusParams,
-    SeverityCounts,
+    append_note, close_stale, collect_reports, collect_reports_with_warnings, finalize_review,
+    register_reviewer, set_initiator_status, summarize_for_applicator, AppendNoteParams,
+    CloseStaleParams, FinalizeReviewParams, InitiatorStatus, NoteRole, NoteType,
+    RegisterReviewerParams, ReportsFilters, ReportsOptions, ReportsView, ReviewEntry, ReviewPhase,
+    ReviewVerdict, ReviewerStatus, SessionFile, SessionLocator, SessionNote,
+    SetInitiatorStatusParams, SeverityCounts, DEFAULT_REPORT_EXT, NOTES_ARCHIVE_FILE,
+    PHASE_NULL_BUCKET, VERDICT_NONE_BUCKET,
 };
+use mpcr::trace::{self, TracePhase};
+use mpcr::warnings::{WarningCode, Warnings};
 use serde_json::Value;
 use std::fs;
 use std::path::Path;
@@ -23,15 +28,47 @@ fn id8_is_8_lower_hex_chars() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn trace_records_only_inside_record_scope() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (guard, events) =
+        trace::record(|| lock::acquire_lock(dir.path(), "owner-a", LockConfig::default()));
+    guard?.release()?;
+    ensure!(events.len() == 1);
+    ensure!(events
+        .iter()
+        .all(|event| event.phase == TracePhase::LockAcquire));
+
+    // Work outside a scope is not carried into the next one.
+    lock::acquire_lock(dir.path(), "owner-a", LockConfig::default())?.release()?;
+    let ((), events) = trace::record(|| ());
+    ensure!(events.is_empty());
+    Ok(())
+}
+
 #[test]
 fn lock_acquire_blocks_until_timeout_then_release() -> anyhow::Result<()> {
     let dir = tempfile::tempdir()?;
     let owner1 = "deadbeef";
     let owner2 = "cafebabe";
 
-    let guard = lock::acquire_lock(dir.path(), owner1, LockConfig { max_retries: 0 })?;
+    let guard = lock::acquire_lock(
+        dir.path(),
+        owner1,
+        LockConfig {
+            max_retries: 0,
+            ..LockConfig::default()
+        },
+    )?;
 
-    let result = lock::acquire_lock(dir.path(), owner2, LockConfig { max_retries: 0 });
+    let result = lock::acquire_lock(
+        dir.path(),
+        owner2,
+        LockConfig {
+            max_retries: 0,
+            ..LockConfig::default()
+        },
+    );
     let Err(err) = result else {
         bail!("second acquire should fail");
     };
@@ -42,7 +79,14 @@ fn lock_acquire_blocks_until_timeout_then_release() -> anyhow::Result<()> {
 
     guard.release()?;
 
-    let guard2 = lock::acquire_lock(dir.path(), owner2, LockConfig { max_retries: 0 })?;
+    let guard2 = lock::acquire_lock(
+        dir.path(),
+        owner2,
+        LockConfig {
+            max_retries: 0,
+            ..LockConfig::default()
+        },
+    )?;
     guard2.release()?;
 
     Ok(())
@@ -67,6 +111,10 @@ fn register_and_finalize_writes_report_and_updates_session() -> anyhow::Result<(
         reviewer_id: Some(reviewer_id.clone()),
         session_id: Some(session_id.clone()),
         parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
         now,
     })?;
 
@@ -95,14 +143,25 @@ fn register_and_finalize_writes_report_and_updates_session() -> anyhow::Result<(
             minor: 2,
             nit: 3,
         },
-        report_markdown: "hello\n".to_string(),
+        report_markdown: Some("hello\n".to_string()),
         now,
+        update_if_finished: false,
+        report_ext: DEFAULT_REPORT_EXT.to_string(),
+        allow_empty_report: false,
+        initiator_status: None,
+        template: None,
+        cleanup_on_failure: false,
     })?;
 
-    ensure!(Path::new(&fin.report_path).exists());
+    ensure!(fin
+        .report_path
+        .as_ref()
+        .is_some_and(|p| Path::new(p).exists()));
     ensure!(
-        fin.report_file
-            == ".local/reports/code_reviews/2026-01-11/12-34-56-789_refs_heads_main_deadbeef.md"
+        fin.report_file.as_deref()
+            == Some(
+                ".local/reports/code_reviews/2026-01-11/12-34-56-789_refs_heads_main_deadbeef.md"
+            )
     );
 
     let raw2 = fs::read_to_string(session.session_file())?;
@@ -121,6 +180,124 @@ fn register_and_finalize_writes_report_and_updates_session() -> anyhow::Result<(
     Ok(())
 }
 
+#[test]
+fn finalize_review_uses_requested_report_extension() -> anyhow::Result<()> {
+    let repo_root = tempfile::tempdir()?;
+    let now = OffsetDateTime::parse("2026-01-11T12:34:56.789Z", &Rfc3339)?;
+    let session_date = now.date();
+    let session = SessionLocator::from_repo_root(repo_root.path(), session_date);
+    register_reviewer(RegisterReviewerParams {
+        repo_root: repo_root.path().to_path_buf(),
+        session_date,
+        session: session.clone(),
+        target_ref: "refs/heads/main".to_string(),
+        reviewer_id: Some("deadbeef".to_string()),
+        session_id: Some("sess0001".to_string()),
+        parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
+        now,
+    })?;
+
+    let base = FinalizeReviewParams {
+        session: session.clone(),
+        reviewer_id: "deadbeef".to_string(),
+        session_id: "sess0001".to_string(),
+        verdict: ReviewVerdict::Approve,
+        counts: SeverityCounts::zero(),
+        report_markdown: Some("plain text\n".to_string()),
+        now,
+        update_if_finished: false,
+        report_ext: ".txt".to_string(),
+        allow_empty_report: false,
+        initiator_status: None,
+        template: None,
+        cleanup_on_failure: false,
+    };
+    let Err(err) = finalize_review(base.clone()) else {
+        bail!("expected a dotted extension to be rejected");
+    };
+    ensure!(err.to_string().contains("invalid report extension"));
+
+    let fin = finalize_review(FinalizeReviewParams {
+        report_ext: "txt".to_string(),
+        ..base
+    })?;
+    let (Some(report_file), Some(report_path)) = (&fin.report_file, &fin.report_path) else {
+        bail!("expected a written report");
+    };
+    ensure!(report_file.ends_with("_refs_heads_main_deadbeef.txt"));
+    ensure!(Path::new(report_path).extension() == Some("txt".as_ref()));
+    ensure!(fs::read_to_string(report_path)? == "plain text\n");
+
+    let raw = fs::read_to_string(session.session_file())?;
+    let session_json: SessionFile = serde_json::from_str(&raw)?;
+    let entry = session_json
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected review entry"))?;
+    ensure!(entry.report_file == fin.report_file);
+    Ok(())
+}
+
+#[test]
+fn finalize_cleanup_on_failure_removes_orphan_report() -> anyhow::Result<()> {
+    let repo_root = tempfile::tempdir()?;
+    let now = OffsetDateTime::parse("2026-01-11T12:34:56.789Z", &Rfc3339)?;
+    let session_date = now.date();
+    let session = SessionLocator::from_repo_root(repo_root.path(), session_date);
+    register_reviewer(RegisterReviewerParams {
+        repo_root: repo_root.path().to_path_buf(),
+        session_date,
+        session: session.clone(),
+        target_ref: "refs/heads/main".to_string(),
+        reviewer_id: Some("deadbeef".to_string()),
+        session_id: Some("sess0001".to_string()),
+        parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
+        now,
+    })?;
+    // A directory squatting on the temp-file path makes the step-3 session write fail.
+    fs::create_dir(session.session_dir().join("_session.json.tmp.deadbeef"))?;
+    let report_count = || -> anyhow::Result<usize> {
+        Ok(fs::read_dir(session.session_dir())?
+            .filter_map(Result::ok)
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+            .count())
+    };
+
+    let params = FinalizeReviewParams {
+        session: session.clone(),
+        reviewer_id: "deadbeef".to_string(),
+        session_id: "sess0001".to_string(),
+        verdict: ReviewVerdict::Approve,
+        counts: SeverityCounts::zero(),
+        report_markdown: Some("report\n".to_string()),
+        now,
+        update_if_finished: false,
+        report_ext: DEFAULT_REPORT_EXT.to_string(),
+        allow_empty_report: false,
+        initiator_status: None,
+        template: None,
+        cleanup_on_failure: true,
+    };
+    ensure!(finalize_review(params.clone()).is_err());
+    ensure!(report_count()? == 0);
+
+    ensure!(finalize_review(FinalizeReviewParams {
+        cleanup_on_failure: false,
+        ..params
+    })
+    .is_err());
+    ensure!(report_count()? == 1);
+    Ok(())
+}
+
 #[test]
 fn register_reviewer_does_not_inherit_initiator_status_from_old_session() -> anyhow::Result<()> {
     let repo_root = tempfile::tempdir()?;
@@ -135,6 +312,10 @@ fn register_reviewer_does_not_inherit_initiator_status_from_old_session() -> any
         reviewer_id: Some("deadbeef".to_string()),
         session_id: Some("sess0001".to_string()),
         parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
         now,
     })?;
 
@@ -154,8 +335,14 @@ fn register_reviewer_does_not_inherit_initiator_status_from_old_session() -> any
         session_id: "sess0001".to_string(),
         verdict: ReviewVerdict::Approve,
         counts: SeverityCounts::zero(),
-        report_markdown: "hello\n".to_string(),
+        report_markdown: Some("hello\n".to_string()),
         now,
+        update_if_finished: false,
+        report_ext: DEFAULT_REPORT_EXT.to_string(),
+        allow_empty_report: false,
+        initiator_status: None,
+        template: None,
+        cleanup_on_failure: false,
     })?;
 
     register_reviewer(RegisterReviewerParams {
@@ -166,6 +353,10 @@ fn register_reviewer_does_not_inherit_initiator_status_from_old_session() -> any
         reviewer_id: Some("cafebabe".to_string()),
         session_id: Some("sess0002".to_string()),
         parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
         now,
     })?;
 
@@ -196,6 +387,10 @@ fn applicator_lock_owner_must_be_id8() -> anyhow::Result<()> {
         reviewer_id: Some("deadbeef".to_string()),
         session_id: Some("sess0001".to_string()),
         parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
         now,
     })?;
 
@@ -234,6 +429,10 @@ fn register_reviewer_is_idempotent_for_same_reviewer_and_session() -> anyhow::Re
         reviewer_id: Some("deadbeef".to_string()),
         session_id: Some("sess0001".to_string()),
         parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
         now,
     })?;
 
@@ -245,6 +444,10 @@ fn register_reviewer_is_idempotent_for_same_reviewer_and_session() -> anyhow::Re
         reviewer_id: Some("deadbeef".to_string()),
         session_id: Some("sess0001".to_string()),
         parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
         now,
     })?;
 
@@ -254,6 +457,54 @@ fn register_reviewer_is_idempotent_for_same_reviewer_and_session() -> anyhow::Re
     Ok(())
 }
 
+#[test]
+fn reports_exclude_reviewer_id_drops_matching_entries() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    for entry in &mut session.reviews {
+        entry.status = ReviewerStatus::InProgress;
+    }
+
+    let all = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters::default(),
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(all.matching_reviews == 3);
+
+    let excluded = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters {
+            exclude_reviewer_ids: vec!["cafebabe".to_string()],
+            ..ReportsFilters::default()
+        },
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(excluded.matching_reviews == 2);
+    ensure!(excluded.reviews.iter().all(|r| r.reviewer_id != "cafebabe"));
+
+    let contradictory = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters {
+            reviewer_id: Some("cafebabe".to_string()),
+            exclude_reviewer_ids: vec!["cafebabe".to_string()],
+            ..ReportsFilters::default()
+        },
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(contradictory.matching_reviews == 0);
+    Ok(())
+}
+
 fn reports_fixture(dir: &tempfile::TempDir) -> (SessionLocator, SessionFile) {
     let session_locator = SessionLocator::new(dir.path().to_path_buf());
     let started_at = "2026-01-11T00:00:00Z";
@@ -263,6 +514,8 @@ fn reports_fixture(dir: &tempfile::TempDir) -> (SessionLocator, SessionFile) {
         timestamp: "2026-01-11T01:30:00Z".to_string(),
         note_type: NoteType::Question,
         content: Value::String("need context".to_string()),
+        tags: Vec::new(),
+        severity: None,
     };
 
     let in_progress = ReviewEntry {
@@ -276,10 +529,13 @@ fn reports_fixture(dir: &tempfile::TempDir) -> (SessionLocator, SessionFile) {
         updated_at: updated_at.to_string(),
         finished_at: None,
         current_phase: Some(ReviewPhase::Ingestion),
+        eta: None,
+        blocking: None,
         verdict: None,
         counts: SeverityCounts::zero(),
         report_file: None,
         notes: vec![note],
+        status_history: Vec::new(),
     };
 
     let blocked = ReviewEntry {
@@ -293,10 +549,13 @@ fn reports_fixture(dir: &tempfile::TempDir) -> (SessionLocator, SessionFile) {
         updated_at: updated_at.to_string(),
         finished_at: None,
         current_phase: None,
+        eta: None,
+        blocking: None,
         verdict: None,
         counts: SeverityCounts::zero(),
         report_file: None,
         notes: Vec::new(),
+        status_history: Vec::new(),
     };
 
     let finished = ReviewEntry {
@@ -310,6 +569,8 @@ fn reports_fixture(dir: &tempfile::TempDir) -> (SessionLocator, SessionFile) {
         updated_at: updated_at.to_string(),
         finished_at: Some("2026-01-11T02:00:00Z".to_string()),
         current_phase: Some(ReviewPhase::ReportWriting),
+        eta: None,
+        blocking: None,
         verdict: Some(ReviewVerdict::Approve),
         counts: SeverityCounts {
             blocker: 0,
@@ -319,6 +580,7 @@ fn reports_fixture(dir: &tempfile::TempDir) -> (SessionLocator, SessionFile) {
         },
         report_file: Some("12-00-00-000_refs_heads_main_feedface.md".to_string()),
         notes: Vec::new(),
+        status_history: Vec::new(),
     };
 
     let session = SessionFile {
@@ -347,7 +609,8 @@ fn reports_view_counts() -> anyhow::Result<()> {
         ReportsView::Open,
         ReportsFilters::default(),
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(open.total_reviews == 3);
     ensure!(open.matching_reviews == 2);
 
@@ -357,7 +620,8 @@ fn reports_view_counts() -> anyhow::Result<()> {
         ReportsView::Closed,
         ReportsFilters::default(),
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(closed.matching_reviews == 1);
 
     let in_progress = collect_reports(
@@ -366,13 +630,268 @@ fn reports_view_counts() -> anyhow::Result<()> {
         ReportsView::InProgress,
         ReportsFilters::default(),
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(in_progress.matching_reviews == 1);
 
     Ok(())
 }
 
 #[test]
+fn reports_filters_only_actionable() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let mut applied = session
+        .reviews
+        .iter()
+        .find(|r| r.reviewer_id == "feedface")
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("fixture has no finished review"))?;
+    applied.reviewer_id = "abad1dea".to_string();
+    applied.initiator_status = InitiatorStatus::Applied;
+    session.reviews.push(applied);
+
+    let result = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Closed,
+        ReportsFilters {
+            only_actionable: true,
+            ..ReportsFilters::default()
+        },
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    let ids: Vec<&str> = result
+        .reviews
+        .iter()
+        .map(|r| r.reviewer_id.as_str())
+        .collect();
+    // The RECEIVED finished review is actionable; the APPLIED one is not.
+    ensure!(ids == ["feedface"], "unexpected reviews: {ids:?}");
+    Ok(())
+}
+
+#[test]
+fn reports_filters_phase_at_least() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    // Reopen the REPORT_WRITING entry so one view holds INGESTION, null, and REPORT_WRITING.
+    for review in &mut session.reviews {
+        review.status = ReviewerStatus::InProgress;
+    }
+
+    let result = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters {
+            phase_at_least: Some(ReviewPhase::Synthesis),
+            ..ReportsFilters::default()
+        },
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    let ids: Vec<&str> = result
+        .reviews
+        .iter()
+        .map(|r| r.reviewer_id.as_str())
+        .collect();
+    ensure!(ids == ["feedface"], "unexpected matches: {ids:?}");
+    ensure!(ReviewPhase::Ingestion.order() < ReviewPhase::Synthesis.order());
+    Ok(())
+}
+
+#[test]
+fn reports_filters_finished_window() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, session) = reports_fixture(&dir);
+    let at = |raw: &str| OffsetDateTime::parse(raw, &Rfc3339);
+    let window = ReportsFilters {
+        finished_after: Some(at("2026-01-11T01:30:00Z")?),
+        finished_before: Some(at("2026-01-11T03:00:00Z")?),
+        ..ReportsFilters::default()
+    };
+
+    // feedface finished at 02:00, inside the window.
+    let closed = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Closed,
+        window.clone(),
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(closed.matching_reviews == 1);
+    ensure!(closed
+        .reviews
+        .first()
+        .is_some_and(|r| r.reviewer_id == "feedface"));
+
+    // Unfinished entries (null finished_at) never match a finished_* filter.
+    let open = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        window,
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(open.matching_reviews == 0);
+
+    let before_finish = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Closed,
+        ReportsFilters {
+            finished_before: Some(at("2026-01-11T02:00:00Z")?),
+            ..ReportsFilters::default()
+        },
+        ReportsOptions::default(),
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(before_finish.matching_reviews == 0);
+    Ok(())
+}
+
+#[test]
+fn reports_count_by_phase_buckets_matching_entries() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let mut second = session
+        .reviews
+        .first()
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("fixture has no reviews"))?;
+    second.reviewer_id = "abad1dea".to_string();
+    session.reviews.push(second);
+
+    let result = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters::default(),
+        ReportsOptions {
+            count_by_phase: true,
+            ..ReportsOptions::default()
+        },
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(result.matching_reviews == 3);
+    ensure!(result.reviews.is_empty());
+    let counts = result
+        .phase_counts
+        .ok_or_else(|| anyhow::anyhow!("missing phase_counts"))?;
+    ensure!(counts.get("INGESTION") == Some(&2));
+    ensure!(counts.get(PHASE_NULL_BUCKET) == Some(&1));
+    ensure!(counts.get("REPORT_WRITING") == Some(&0));
+    ensure!(counts.len() == ReviewPhase::value_variants().len() + 1);
+    Ok(())
+}
+
+#[test]
+fn reports_verdict_summary_histograms_closed_entries() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let finished = session
+        .reviews
+        .iter()
+        .find(|r| r.reviewer_id == "feedface")
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("fixture has no finished review"))?;
+    let mut approve = finished.clone();
+    approve.reviewer_id = "abad1dea".to_string();
+    let mut block = finished;
+    block.reviewer_id = "0badf00d".to_string();
+    block.verdict = Some(ReviewVerdict::Block);
+    session.reviews.extend([approve, block]);
+
+    let options = ReportsOptions {
+        verdict_summary: true,
+        ..ReportsOptions::default()
+    };
+    let result = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Closed,
+        ReportsFilters::default(),
+        options,
+        OffsetDateTime::now_utc(),
+    )?;
+    ensure!(result.reviews.is_empty());
+    let summary = result
+        .verdict_summary
+        .ok_or_else(|| anyhow::anyhow!("missing verdict_summary"))?;
+    ensure!(summary.total == 3);
+    ensure!(summary.counts.get("APPROVE") == Some(&2));
+    ensure!(summary.counts.get("BLOCK") == Some(&1));
+    ensure!(summary.counts.get("REQUEST_CHANGES") == Some(&0));
+    ensure!(summary.counts.get(VERDICT_NONE_BUCKET) == Some(&0));
+
+    let open = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters::default(),
+        options,
+        OffsetDateTime::now_utc(),
+    );
+    ensure!(open.is_err());
+    Ok(())
+}
+
+#[test]
+fn reports_dedupe_notes_collapses_identical_notes() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let entry = session
+        .reviews
+        .first_mut()
+        .ok_or_else(|| anyhow::anyhow!("fixture has no reviews"))?;
+    let note = entry
+        .notes
+        .first()
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("fixture entry has no notes"))?;
+    entry.notes.push(note);
+
+    let collect = |dedupe_notes| {
+        collect_reports(
+            &session,
+            &session_locator,
+            ReportsView::Open,
+            ReportsFilters {
+                reviewer_id: Some("deadbeef".to_string()),
+                ..ReportsFilters::default()
+            },
+            ReportsOptions {
+                include_notes: true,
+                dedupe_notes,
+                ..ReportsOptions::default()
+            },
+            OffsetDateTime::now_utc(),
+        )
+    };
+
+    let raw = collect(false)?;
+    let raw_review = raw
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("missing review"))?;
+    ensure!(raw_review.notes_count == 2);
+
+    let deduped = collect(true)?;
+    let review = deduped
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("missing review"))?;
+    ensure!(review.notes_count == 1);
+    ensure!(review.notes.as_ref().map(Vec::len) == Some(1));
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
 fn reports_filters_basic_fields() -> anyhow::Result<()> {
     let dir = tempfile::tempdir()?;
     let (session_locator, session) = reports_fixture(&dir);
@@ -391,9 +910,11 @@ fn reports_filters_basic_fields() -> anyhow::Result<()> {
             phases: Vec::new(),
             only_with_report: false,
             only_with_notes: false,
+            ..ReportsFilters::default()
         },
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(target_filtered.matching_reviews == 1);
 
     let status_filtered = collect_reports(
@@ -410,9 +931,11 @@ fn reports_filters_basic_fields() -> anyhow::Result<()> {
             phases: Vec::new(),
             only_with_report: false,
             only_with_notes: false,
+            ..ReportsFilters::default()
         },
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(status_filtered.matching_reviews == 1);
 
     let initiator_filtered = collect_reports(
@@ -429,9 +952,11 @@ fn reports_filters_basic_fields() -> anyhow::Result<()> {
             phases: Vec::new(),
             only_with_report: false,
             only_with_notes: false,
+            ..ReportsFilters::default()
         },
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(initiator_filtered.matching_reviews == 1);
 
     let verdict_filtered = collect_reports(
@@ -448,9 +973,11 @@ fn reports_filters_basic_fields() -> anyhow::Result<()> {
             phases: Vec::new(),
             only_with_report: false,
             only_with_notes: false,
+            ..ReportsFilters::default()
         },
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(verdict_filtered.matching_reviews == 1);
 
     let phase_filtered = collect_reports(
@@ -467,9 +994,11 @@ fn reports_filters_basic_fields() -> anyhow::Result<()> {
             phases: vec![ReviewPhase::Ingestion],
             only_with_report: false,
             only_with_notes: false,
+            ..ReportsFilters::default()
         },
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(phase_filtered.matching_reviews == 1);
 
     Ok(())
@@ -494,12 +1023,15 @@ fn reports_filters_only_notes_and_report() -> anyhow::Result<()> {
             phases: Vec::new(),
             only_with_report: false,
             only_with_notes: true,
+            ..ReportsFilters::default()
         },
         ReportsOptions {
             include_notes: true,
             include_report_contents: false,
+            ..ReportsOptions::default()
         },
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(only_notes.matching_reviews == 1);
     let notes_entry = only_notes
         .reviews
@@ -524,9 +1056,11 @@ fn reports_filters_only_notes_and_report() -> anyhow::Result<()> {
             phases: Vec::new(),
             only_with_report: true,
             only_with_notes: false,
+            ..ReportsFilters::default()
         },
         ReportsOptions::default(),
-    );
+        OffsetDateTime::now_utc(),
+    )?;
     ensure!(only_report.matching_reviews == 1);
     let report_entry = only_report
         .reviews
@@ -558,10 +1092,13 @@ fn reports_include_report_contents() -> anyhow::Result<()> {
         updated_at: "2026-01-11T01:00:00Z".to_string(),
         finished_at: Some("2026-01-11T02:00:00Z".to_string()),
         current_phase: Some(ReviewPhase::ReportWriting),
+        eta: None,
+        blocking: None,
         verdict: Some(ReviewVerdict::Approve),
         counts: SeverityCounts::zero(),
         report_file: Some(report_file.to_string()),
         notes: Vec::new(),
+        status_history: Vec::new(),
     };
 
     let session = SessionFile {
@@ -580,8 +1117,10 @@ fn reports_include_report_contents() -> anyhow::Result<()> {
         ReportsOptions {
             include_notes: false,
             include_report_contents: true,
+            ..ReportsOptions::default()
         },
-    );
+        OffsetDateTime::now_utc(),
+    )?;
 
     ensure!(result.matching_reviews == 1);
     let entry = result
@@ -593,3 +1132,662 @@ fn reports_include_report_contents() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn reports_include_report_size_stats_without_reading() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, session) = reports_fixture(&dir);
+    let options = ReportsOptions {
+        include_report_size: true,
+        ..ReportsOptions::default()
+    };
+
+    let missing = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Closed,
+        ReportsFilters::default(),
+        options,
+        OffsetDateTime::now_utc(),
+    )?;
+    let entry = missing
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected report entry"))?;
+    ensure!(entry.report_bytes.is_none());
+    ensure!(entry
+        .report_error
+        .as_deref()
+        .is_some_and(|e| e.starts_with("stat report file")));
+
+    fs::write(
+        dir.path().join("12-00-00-000_refs_heads_main_feedface.md"),
+        "0123456789",
+    )?;
+    let present = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Closed,
+        ReportsFilters::default(),
+        options,
+        OffsetDateTime::now_utc(),
+    )?;
+    let entry = present
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected report entry"))?;
+    ensure!(entry.report_bytes == Some(10));
+    ensure!(entry.report_error.is_none());
+    ensure!(entry.report_contents.is_none());
+    Ok(())
+}
+
+#[test]
+fn reports_report_grep_keeps_only_matching_reports() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let mut second = session
+        .reviews
+        .last()
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("fixture has no reviews"))?;
+    second.reviewer_id = "abad1dea".to_string();
+    second.report_file = Some("12-00-00-000_refs_heads_main_abad1dea.md".to_string());
+    let mut unreadable = second.clone();
+    unreadable.reviewer_id = "0badf00d".to_string();
+    unreadable.report_file = Some("missing.md".to_string());
+    session.reviews.extend([second, unreadable]);
+    fs::write(
+        dir.path().join("12-00-00-000_refs_heads_main_feedface.md"),
+        "Calls SessionLocator::new twice.\n",
+    )?;
+    fs::write(
+        dir.path().join("12-00-00-000_refs_heads_main_abad1dea.md"),
+        "Nothing of note.\n",
+    )?;
+
+    let grep = |needle: &str, ignore_case: bool, warnings: &mut Warnings| {
+        collect_reports_with_warnings(
+            &session,
+            &session_locator,
+            ReportsView::Closed,
+            ReportsFilters {
+                report_grep: Some(needle.to_string()),
+                report_grep_ignore_case: ignore_case,
+                ..ReportsFilters::default()
+            },
+            ReportsOptions::default(),
+            OffsetDateTime::now_utc(),
+            warnings,
+        )
+    };
+
+    let mut warnings = Warnings::new();
+    let exact = grep("SessionLocator", false, &mut warnings)?;
+    let ids: Vec<&str> = exact
+        .reviews
+        .iter()
+        .map(|r| r.reviewer_id.as_str())
+        .collect();
+    ensure!(ids == ["feedface"], "unexpected matches: {ids:?}");
+    let [warning] = warnings.as_slice() else {
+        bail!("expected one warning, got {:?}", warnings.as_slice());
+    };
+    ensure!(warning.reviewer_id.as_deref() == Some("0badf00d"));
+
+    let mut warnings = Warnings::new();
+    ensure!(grep("sessionlocator", false, &mut warnings)?.matching_reviews == 0);
+    ensure!(grep("sessionlocator", true, &mut warnings)?.matching_reviews == 1);
+    Ok(())
+}
+
+#[test]
+fn reports_include_age_computes_ages_from_now() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    if let Some(blocked) = session
+        .reviews
+        .iter_mut()
+        .find(|r| r.reviewer_id == "cafebabe")
+    {
+        blocked.updated_at = "not-a-timestamp".to_string();
+    }
+    let now = OffsetDateTime::parse("2026-01-11T03:00:00Z", &Rfc3339)?;
+    let mut warnings = Warnings::new();
+
+    let result = collect_reports_with_warnings(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters::default(),
+        ReportsOptions {
+            include_age: true,
+            ..ReportsOptions::default()
+        },
+        now,
+        &mut warnings,
+    )?;
+
+    let age_of = |reviewer_id: &str| {
+        result
+            .reviews
+            .iter()
+            .find(|r| r.reviewer_id == reviewer_id)
+            .and_then(|r| r.age)
+            .ok_or_else(|| anyhow::anyhow!("missing age for {reviewer_id}"))
+    };
+    let fresh = age_of("deadbeef")?;
+    ensure!(fresh.updated_age_secs == Some(2 * 3600));
+    ensure!(fresh.started_age_secs == Some(3 * 3600));
+    let broken = age_of("cafebabe")?;
+    ensure!(broken.updated_age_secs.is_none());
+    ensure!(broken.started_age_secs == Some(3 * 3600));
+    let [warning] = warnings.as_slice() else {
+        bail!(
+            "expected exactly one warning, got {:?}",
+            warnings.as_slice()
+        );
+    };
+    ensure!(warning.code == WarningCode::TimestampParse);
+    ensure!(warning.reviewer_id.as_deref() == Some("cafebabe"));
+    Ok(())
+}
+
+#[test]
+fn reports_missing_report_file_produces_structured_warning() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, session) = reports_fixture(&dir);
+    let mut warnings = Warnings::new();
+
+    let result = collect_reports_with_warnings(
+        &session,
+        &session_locator,
+        ReportsView::Closed,
+        ReportsFilters::default(),
+        ReportsOptions {
+            include_report_contents: true,
+            ..ReportsOptions::default()
+        },
+        OffsetDateTime::now_utc(),
+        &mut warnings,
+    )?;
+
+    let entry = result
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected report entry"))?;
+    ensure!(entry.report_error.is_some());
+    let [warning] = warnings.as_slice() else {
+        bail!(
+            "expected exactly one warning, got {:?}",
+            warnings.as_slice()
+        );
+    };
+    ensure!(warning.code == WarningCode::ReportRead);
+    ensure!(warning.reviewer_id.as_deref() == Some("feedface"));
+    ensure!(warning.session_id.as_deref() == Some("sess0003"));
+    ensure!(warning
+        .path
+        .as_deref()
+        .is_some_and(|p| p.ends_with("12-00-00-000_refs_heads_main_feedface.md")));
+    Ok(())
+}
+
+#[test]
+fn finalize_update_if_finished_accepts_identical_report_only() -> anyhow::Result<()> {
+    let repo_root = tempfile::tempdir()?;
+    let now = OffsetDateTime::parse("2026-01-11T12:34:56.789Z", &Rfc3339)?;
+    let session_date = now.date();
+    let session = SessionLocator::from_repo_root(repo_root.path(), session_date);
+
+    register_reviewer(RegisterReviewerParams {
+        repo_root: repo_root.path().to_path_buf(),
+        session_date,
+        session: session.clone(),
+        target_ref: "refs/heads/main".to_string(),
+        reviewer_id: Some("deadbeef".to_string()),
+        session_id: Some("sess0001".to_string()),
+        parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
+        now,
+    })?;
+
+    let finalize = |markdown: &str, major: u64| {
+        finalize_review(FinalizeReviewParams {
+            session: session.clone(),
+            reviewer_id: "deadbeef".to_string(),
+            session_id: "sess0001".to_string(),
+            verdict: ReviewVerdict::RequestChanges,
+            counts: SeverityCounts {
+                blocker: 0,
+                major,
+                minor: 0,
+                nit: 0,
+            },
+            report_markdown: Some(markdown.to_string()),
+            now,
+            update_if_finished: true,
+            report_ext: DEFAULT_REPORT_EXT.to_string(),
+            allow_empty_report: false,
+            initiator_status: None,
+            template: None,
+            cleanup_on_failure: false,
+        })
+    };
+
+    let first = finalize("report body", 1)?;
+    let retry = finalize("report body", 2)?;
+    ensure!(retry.report_file == first.report_file);
+    ensure!(retry.report_path == first.report_path);
+    let report_path = first
+        .report_path
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("expected a written report"))?;
+    ensure!(fs::read_to_string(report_path)? == "report body\n");
+
+    let raw = fs::read_to_string(session.session_file())?;
+    let session_json: SessionFile = serde_json::from_str(&raw)?;
+    let entry = session_json
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected review entry"))?;
+    ensure!(entry.counts.major == 2);
+
+    let Err(err) = finalize("different body", 2) else {
+        bail!("differing report contents should error");
+    };
+    ensure!(err.to_string().contains("different contents"));
+    Ok(())
+}
+
+#[test]
+fn register_require_active_session_refuses_closed_or_missing_session() -> anyhow::Result<()> {
+    let repo_root = tempfile::tempdir()?;
+    let now = OffsetDateTime::parse("2026-01-11T12:34:56.789Z", &Rfc3339)?;
+    let session_date = now.date();
+    let session = SessionLocator::from_repo_root(repo_root.path(), session_date);
+    let params = |reviewer_id: &str, require_active_session: bool| RegisterReviewerParams {
+        repo_root: repo_root.path().to_path_buf(),
+        session_date,
+        session: session.clone(),
+        target_ref: "refs/heads/main".to_string(),
+        reviewer_id: Some(reviewer_id.to_string()),
+        session_id: None,
+        parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session,
+        now,
+    };
+
+    let Err(err) = register_reviewer(params("cafebabe", true)) else {
+        bail!("joining a missing session should error");
+    };
+    ensure!(err.to_string().contains("no active session"));
+    ensure!(!session.session_dir().exists());
+
+    let first = register_reviewer(params("deadbeef", false))?;
+    finalize_review(FinalizeReviewParams {
+        session: session.clone(),
+        reviewer_id: "deadbeef".to_string(),
+        session_id: first.session_id,
+        verdict: ReviewVerdict::Approve,
+        counts: SeverityCounts {
+            blocker: 0,
+            major: 0,
+            minor: 0,
+            nit: 0,
+        },
+        report_markdown: None,
+        now,
+        update_if_finished: false,
+        report_ext: DEFAULT_REPORT_EXT.to_string(),
+        allow_empty_report: false,
+        initiator_status: None,
+        template: None,
+        cleanup_on_failure: false,
+    })?;
+
+    let Err(err) = register_reviewer(params("cafebabe", true)) else {
+        bail!("joining a closed session should error");
+    };
+    ensure!(err.to_string().contains("no active session"));
+    let raw = fs::read_to_string(session.session_file())?;
+    let session_json: SessionFile = serde_json::from_str(&raw)?;
+    ensure!(session_json.reviews.len() == 1);
+    ensure!(session_json.reviewers == vec!["deadbeef".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn reports_open_older_than_matches_stale_reviews_only() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let now = OffsetDateTime::parse("2026-01-11T12:00:00Z", &Rfc3339)?;
+    let two_hours_ago = (now - time::Duration::hours(2)).format(&Rfc3339)?;
+    let fresh = (now - time::Duration::minutes(5)).format(&Rfc3339)?;
+    for entry in &mut session.reviews {
+        entry.updated_at = if entry.reviewer_id == "deadbeef" {
+            two_hours_ago.clone()
+        } else {
+            fresh.clone()
+        };
+    }
+
+    let filters = ReportsFilters {
+        open_older_than_secs: Some(60 * 60),
+        ..ReportsFilters::default()
+    };
+    let stale = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        filters.clone(),
+        ReportsOptions::default(),
+        now,
+    )?;
+    ensure!(stale.matching_reviews == 1);
+    let entry = stale
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected review entry"))?;
+    ensure!(entry.reviewer_id == "deadbeef");
+
+    for entry in &mut session.reviews {
+        entry.updated_at = "not-a-timestamp".to_string();
+    }
+    let result = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        filters,
+        ReportsOptions::default(),
+        now,
+    );
+    let Err(err) = result else {
+        bail!("unparseable updated_at should error");
+    };
+    ensure!(format!("{err:#}").contains("updated_at"));
+    Ok(())
+}
+
+#[test]
+fn reports_note_since_matches_by_newest_note_time() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let now = OffsetDateTime::parse("2026-01-11T02:00:00Z", &Rfc3339)?;
+    for entry in &mut session.reviews {
+        if entry.reviewer_id == "cafebabe" {
+            entry.notes.push(SessionNote {
+                role: NoteRole::Reviewer,
+                timestamp: "2026-01-11T00:10:00Z".to_string(),
+                note_type: NoteType::Question,
+                content: Value::String("stale question".to_string()),
+                tags: Vec::new(),
+                severity: None,
+            });
+            // A recent update must not count as recent note activity.
+            entry.updated_at = "2026-01-11T01:59:00Z".to_string();
+        }
+    }
+
+    let result = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters {
+            note_since_secs: Some(45 * 60),
+            ..ReportsFilters::default()
+        },
+        ReportsOptions::default(),
+        now,
+    )?;
+    ensure!(result.matching_reviews == 1);
+    let entry = result
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected review entry"))?;
+    ensure!(entry.reviewer_id == "deadbeef");
+    Ok(())
+}
+
+#[test]
+fn reports_sla_breach_flags_old_open_reviews() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let now = OffsetDateTime::parse("2026-01-11T06:00:00Z", &Rfc3339)?;
+    for entry in &mut session.reviews {
+        if entry.reviewer_id == "cafebabe" {
+            entry.started_at = "2026-01-11T05:00:00Z".to_string();
+        }
+    }
+
+    let result = collect_reports(
+        &session,
+        &session_locator,
+        ReportsView::Open,
+        ReportsFilters {
+            sla_breach_secs: Some(4 * 60 * 60),
+            ..ReportsFilters::default()
+        },
+        ReportsOptions::default(),
+        now,
+    )?;
+    ensure!(result.matching_reviews == 1);
+    let entry = result
+        .reviews
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected review entry"))?;
+    ensure!(entry.reviewer_id == "deadbeef");
+    ensure!(entry.breach_secs == Some(2 * 60 * 60));
+    Ok(())
+}
+
+#[test]
+fn applicator_summary_lists_unapplied_finished_reviews() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (_, session) = reports_fixture(&dir);
+
+    let summary = summarize_for_applicator(&session);
+    ensure!(summary.total_reviews == 3);
+    ensure!(summary.verdicts.approve == 1);
+    ensure!(summary.verdicts.none == 2);
+    ensure!(summary.total_blockers == 0);
+    ensure!(summary.total_majors == 1);
+    ensure!(summary.actionable.len() == 1);
+    let actionable = summary
+        .actionable
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("expected actionable entry"))?;
+    ensure!(actionable.reviewer_id == "feedface");
+    ensure!(actionable.initiator_status == InitiatorStatus::Received);
+    Ok(())
+}
+
+#[test]
+fn close_stale_errors_out_only_idle_open_reviews() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (session_locator, mut session) = reports_fixture(&dir);
+    let now = OffsetDateTime::parse("2026-01-11T12:00:00Z", &Rfc3339)?;
+    let two_hours_ago = (now - time::Duration::hours(2)).format(&Rfc3339)?;
+    let fresh = (now - time::Duration::minutes(5)).format(&Rfc3339)?;
+    for entry in &mut session.reviews {
+        entry.updated_at = if entry.reviewer_id == "cafebabe" {
+            fresh.clone()
+        } else {
+            two_hours_ago.clone()
+        };
+    }
+    fs::write(
+        dir.path().join("_session.json"),
+        serde_json::to_vec_pretty(&session)?,
+    )?;
+    let params = CloseStaleParams {
+        session: session_locator,
+        older_than_secs: 60 * 60,
+        dry_run: true,
+        now,
+        lock_owner: "0badc0de".to_string(),
+    };
+
+    let preview = close_stale(&params)?;
+    ensure!(preview.dry_run);
+    ensure!(preview.closed.len() == 1);
+    ensure!(preview
+        .closed
+        .first()
+        .is_some_and(|r| r.reviewer_id == "deadbeef" && r.status == ReviewerStatus::InProgress));
+    let untouched: SessionFile =
+        serde_json::from_slice(&fs::read(dir.path().join("_session.json"))?)?;
+    ensure!(untouched
+        .reviews
+        .iter()
+        .all(|r| r.status != ReviewerStatus::Error));
+
+    let res = close_stale(&CloseStaleParams {
+        dry_run: false,
+        ..params
+    })?;
+    ensure!(res.closed.len() == 1);
+    let after: SessionFile = serde_json::from_slice(&fs::read(dir.path().join("_session.json"))?)?;
+    let find = |reviewer_id: &str| {
+        after
+            .reviews
+            .iter()
+            .find(|r| r.reviewer_id == reviewer_id)
+            .ok_or_else(|| anyhow::anyhow!("missing {reviewer_id}"))
+    };
+    let stale = find("deadbeef")?;
+    ensure!(stale.status == ReviewerStatus::Error);
+    ensure!(stale.finished_at.as_deref() == Some("2026-01-11T12:00:00Z"));
+    ensure!(stale
+        .notes
+        .last()
+        .is_some_and(|n| n.note_type == NoteType::ErrorDetail && n.role == NoteRole::Reviewer));
+    let open = find("cafebabe")?;
+    ensure!(open.status == ReviewerStatus::Blocked && open.finished_at.is_none());
+    ensure!(find("feedface")?.status == ReviewerStatus::Finished);
+    Ok(())
+}
+
+#[test]
+fn append_note_archives_pruned_notes_only_after_the_session_write() -> anyhow::Result<()> {
+    let repo_root = tempfile::tempdir()?;
+    let now = OffsetDateTime::parse("2026-01-11T12:34:56.789Z", &Rfc3339)?;
+    let session_date = now.date();
+    let session = SessionLocator::from_repo_root(repo_root.path(), session_date);
+    register_reviewer(RegisterReviewerParams {
+        repo_root: repo_root.path().to_path_buf(),
+        session_date,
+        session: session.clone(),
+        target_ref: "refs/heads/main".to_string(),
+        reviewer_id: Some("deadbeef".to_string()),
+        session_id: Some("sess0001".to_string()),
+        parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
+        now,
+    })?;
+    let note = |locator: &SessionLocator, content: &str| {
+        append_note(AppendNoteParams {
+            session: locator.clone(),
+            reviewer_id: "deadbeef".to_string(),
+            session_id: "sess0001".to_string(),
+            role: NoteRole::Reviewer,
+            note_type: NoteType::Question,
+            content: Value::String(content.to_string()),
+            now,
+            lock_owner: "deadbeef".to_string(),
+            dedupe: false,
+            max_notes: Some(1),
+            archive_pruned: true,
+            tags: Vec::new(),
+            severity: None,
+            initiator_status: None,
+        })
+    };
+    let archive_path = session.session_dir().join(NOTES_ARCHIVE_FILE);
+
+    note(&session, "first")?;
+    // An unusable temp dir makes the session write fail after the prune was computed.
+    let mut broken = session.clone();
+    broken.tmp_dir = Some(repo_root.path().join("missing"));
+    ensure!(note(&broken, "second").is_err());
+    ensure!(!archive_path.exists());
+
+    note(&session, "third")?;
+    let archive = fs::read_to_string(&archive_path)?;
+    let archived: Vec<Value> = archive
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    let [archived_note] = archived.as_slice() else {
+        bail!("expected exactly one archived note, got {archived:?}");
+    };
+    ensure!(archived_note.get("content").and_then(Value::as_str) == Some("first"));
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn fs_lock_writer_waits_for_held_flock_sidecar() -> anyhow::Result<()> {
+    let repo_root = tempfile::tempdir()?;
+    let now = OffsetDateTime::parse("2026-01-11T12:34:56.789Z", &Rfc3339)?;
+    let session_date = now.date();
+    let session = SessionLocator::from_repo_root(repo_root.path(), session_date).with_fs_lock(true);
+    register_reviewer(RegisterReviewerParams {
+        repo_root: repo_root.path().to_path_buf(),
+        session_date,
+        session: session.clone(),
+        target_ref: "refs/heads/main".to_string(),
+        reviewer_id: Some("deadbeef".to_string()),
+        session_id: Some("sess0001".to_string()),
+        parent_id: None,
+        strict_ref: false,
+        max_reviewers: None,
+        observe_only: false,
+        require_active_session: false,
+        now,
+    })?;
+
+    // Another client holds only the advisory lock (no `_session.json.lock`).
+    let sidecar = fs::File::open(lock::fs_lock_file_path(session.session_dir()))?;
+    sidecar.lock()?;
+
+    let writer = {
+        let params = SetInitiatorStatusParams {
+            session: session.clone(),
+            reviewer_id: "deadbeef".to_string(),
+            session_id: "sess0001".to_string(),
+            initiator_status: InitiatorStatus::Applied,
+            now,
+            lock_owner: "lock0001".to_string(),
+        };
+        std::thread::spawn(move || set_initiator_status(&params))
+    };
+    std::thread::sleep(std::time::Duration::from_millis(300));
+    ensure!(!writer.is_finished());
+    let read_status = || -> anyhow::Result<InitiatorStatus> {
+        let raw = fs::read_to_string(session.session_file())?;
+        let session_json: SessionFile = serde_json::from_str(&raw)?;
+        session_json
+            .reviews
+            .first()
+            .map(|entry| entry.initiator_status)
+            .ok_or_else(|| anyhow::anyhow!("expected review entry"))
+    };
+    ensure!(read_status()? != InitiatorStatus::Applied);
+
+    sidecar.unlock()?;
+    writer
+        .join()
+        .map_err(|_| anyhow::anyhow!("writer thread panicked"))??;
+    ensure!(read_status()? == InitiatorStatus::Applied);
+    Ok(())
+}